@@ -0,0 +1,288 @@
+use crate::error::ErrorMemoria;
+use std::collections::{BTreeMap, BTreeSet};
+
+// --- SISTEMA BUDDY (COMPAÑEROS BINARIOS) ---
+
+/// Bloque más pequeño por defecto, en KB (BUDDY_MIN_BLOCK)
+pub const BLOQUE_MINIMO_BUDDY: usize = 64;
+
+/// Bloque entregado a un proceso
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AsignacionBuddy {
+    pub pid: usize,
+    pub orden: u32,        // El bloque mide 2^orden KB
+    pub solicitado: usize, // KB que pidió el proceso
+}
+
+impl AsignacionBuddy {
+    pub fn tamaño(&self) -> usize {
+        1 << self.orden
+    }
+
+    /// KB del bloque que el proceso no usa (redondeo a potencia de dos)
+    pub fn fragmentacion_interna(&self) -> usize {
+        self.tamaño() - self.solicitado
+    }
+}
+
+/// Nodo del árbol buddy tal como se dibuja: libre, asignado o dividido
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodoBuddy {
+    Libre,
+    Asignado(AsignacionBuddy),
+    Dividido,
+}
+
+/// Asignador buddy binario: las peticiones se redondean a potencia de dos,
+/// los bloques se parten por la mitad hasta el orden pedido y, al liberarse,
+/// se fusionan con su compañero (la dirección que difiere en el bit del orden)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsignadorBuddy {
+    pub tamaño_kb: usize,         // KB gestionados (tamaño de la raíz)
+    pub orden_minimo: u32,        // Bloque más pequeño: 2^orden_minimo KB
+    pub orden_maximo: u32,        // Bloque raíz: 2^orden_maximo KB
+    libres: Vec<BTreeSet<usize>>, // Inicios libres por orden (índice = orden - mínimo)
+    asignados: BTreeMap<usize, AsignacionBuddy>, // Bloques ocupados por dirección de inicio
+    pub asignaciones_fallidas: usize,
+}
+
+/// Menor orden `k` con 2^k >= `kb`
+fn orden_para(kb: usize) -> u32 {
+    kb.max(1).next_power_of_two().trailing_zeros()
+}
+
+impl AsignadorBuddy {
+    /// La raíz es la mayor potencia de dos que cabe en `tamaño_kb`; el resto
+    /// de la RAM queda fuera del asignador
+    pub fn new(tamaño_kb: usize, bloque_minimo_kb: usize) -> Self {
+        let orden_maximo = if tamaño_kb == 0 {
+            0
+        } else {
+            usize::BITS - 1 - tamaño_kb.leading_zeros()
+        };
+        let orden_minimo = orden_para(bloque_minimo_kb).min(orden_maximo);
+        let mut libres = vec![BTreeSet::new(); (orden_maximo - orden_minimo + 1) as usize];
+        if tamaño_kb > 0 {
+            libres[(orden_maximo - orden_minimo) as usize].insert(0);
+        }
+        Self {
+            tamaño_kb: if tamaño_kb == 0 {
+                0
+            } else {
+                1 << orden_maximo
+            },
+            orden_minimo,
+            orden_maximo,
+            libres,
+            asignados: BTreeMap::new(),
+            asignaciones_fallidas: 0,
+        }
+    }
+
+    fn lista(&mut self, orden: u32) -> &mut BTreeSet<usize> {
+        &mut self.libres[(orden - self.orden_minimo) as usize]
+    }
+
+    /// Reserva un bloque de 2^k KB (el menor que cubre `tamaño`) partiendo
+    /// bloques mayores si hace falta. Devuelve la dirección de inicio.
+    pub fn asignar(&mut self, pid: usize, tamaño: usize) -> Result<usize, ErrorMemoria> {
+        let orden = orden_para(tamaño).max(self.orden_minimo);
+        let disponible = (orden..=self.orden_maximo)
+            .find(|&k| !self.libres[(k - self.orden_minimo) as usize].is_empty());
+        let Some(mut k) = disponible else {
+            self.asignaciones_fallidas += 1;
+            return Err(ErrorMemoria::SinHueco {
+                solicitado: tamaño,
+                mayor_hueco: self.mayor_bloque_libre(),
+                libre: self.libre_total(),
+            });
+        };
+
+        let inicio = self.lista(k).pop_first().unwrap_or(0);
+        // Partir: la mitad alta de cada división queda libre en el orden inferior
+        while k > orden {
+            k -= 1;
+            self.lista(k).insert(inicio + (1 << k));
+        }
+        self.asignados.insert(
+            inicio,
+            AsignacionBuddy {
+                pid,
+                orden,
+                solicitado: tamaño,
+            },
+        );
+        Ok(inicio)
+    }
+
+    /// Libera el bloque del proceso y lo fusiona con su compañero mientras
+    /// este también esté libre. Devuelve si el proceso tenía bloque.
+    pub fn liberar(&mut self, pid: usize) -> bool {
+        let Some((&inicio, asignacion)) = self.asignados.iter().find(|(_, a)| a.pid == pid) else {
+            return false;
+        };
+        let mut orden = asignacion.orden;
+        let mut inicio = inicio;
+        self.asignados.remove(&inicio);
+
+        while orden < self.orden_maximo {
+            let compañero = inicio ^ (1 << orden);
+            if !self.lista(orden).remove(&compañero) {
+                break;
+            }
+            inicio = inicio.min(compañero);
+            orden += 1;
+        }
+        self.lista(orden).insert(inicio);
+        true
+    }
+
+    /// Dirección y datos del bloque del proceso
+    pub fn bloque_de(&self, pid: usize) -> Option<(usize, &AsignacionBuddy)> {
+        self.asignados
+            .iter()
+            .find(|(_, a)| a.pid == pid)
+            .map(|(&inicio, a)| (inicio, a))
+    }
+
+    /// Bloques ocupados por dirección de inicio
+    pub fn asignados(&self) -> impl Iterator<Item = (usize, &AsignacionBuddy)> {
+        self.asignados.iter().map(|(&inicio, a)| (inicio, a))
+    }
+
+    /// Listas libres de cada orden, del mayor al menor: (orden, inicios)
+    pub fn listas_libres(&self) -> impl Iterator<Item = (u32, &BTreeSet<usize>)> {
+        (self.orden_minimo..=self.orden_maximo)
+            .rev()
+            .map(|k| (k, &self.libres[(k - self.orden_minimo) as usize]))
+    }
+
+    /// Recorrido en preorden del árbol: (profundidad, inicio, orden, nodo)
+    pub fn arbol(&self) -> Vec<(usize, usize, u32, NodoBuddy)> {
+        let mut nodos = Vec::new();
+        if self.tamaño_kb > 0 {
+            self.recorrer(0, self.orden_maximo, 0, &mut nodos);
+        }
+        nodos
+    }
+
+    fn recorrer(
+        &self,
+        inicio: usize,
+        orden: u32,
+        profundidad: usize,
+        nodos: &mut Vec<(usize, usize, u32, NodoBuddy)>,
+    ) {
+        let nodo = match self.asignados.get(&inicio) {
+            Some(a) if a.orden == orden => NodoBuddy::Asignado(*a),
+            _ if self.libres[(orden - self.orden_minimo) as usize].contains(&inicio) => {
+                NodoBuddy::Libre
+            }
+            _ => NodoBuddy::Dividido,
+        };
+        nodos.push((profundidad, inicio, orden, nodo));
+        if nodo == NodoBuddy::Dividido && orden > self.orden_minimo {
+            self.recorrer(inicio, orden - 1, profundidad + 1, nodos);
+            self.recorrer(
+                inicio + (1 << (orden - 1)),
+                orden - 1,
+                profundidad + 1,
+                nodos,
+            );
+        }
+    }
+
+    /// KB libres sumando todas las listas
+    pub fn libre_total(&self) -> usize {
+        self.listas_libres()
+            .map(|(k, lista)| lista.len() << k)
+            .sum()
+    }
+
+    pub fn mayor_bloque_libre(&self) -> usize {
+        self.listas_libres()
+            .find(|(_, lista)| !lista.is_empty())
+            .map_or(0, |(k, _)| 1 << k)
+    }
+
+    /// KB desperdiciados dentro de los bloques asignados
+    pub fn fragmentacion_interna(&self) -> usize {
+        self.asignados
+            .values()
+            .map(AsignacionBuddy::fragmentacion_interna)
+            .sum()
+    }
+
+    /// Porcentaje de la memoria gestionada que está en bloques asignados
+    pub fn utilizacion(&self) -> f64 {
+        if self.tamaño_kb == 0 {
+            return 0.0;
+        }
+        ((self.tamaño_kb - self.libre_total()) as f64 / self.tamaño_kb as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn libres_por_orden(buddy: &AsignadorBuddy) -> Vec<(u32, Vec<usize>)> {
+        buddy
+            .listas_libres()
+            .filter(|(_, lista)| !lista.is_empty())
+            .map(|(k, lista)| (k, lista.iter().copied().collect()))
+            .collect()
+    }
+
+    #[test]
+    fn asignar_parte_hasta_el_orden_pedido() {
+        let mut buddy = AsignadorBuddy::new(1024, 64);
+        // 100 KB se redondea a 128: la raíz se parte en 512, 256 y 128
+        assert_eq!(buddy.asignar(1, 100), Ok(0));
+        assert_eq!(
+            libres_por_orden(&buddy),
+            [(9, vec![512]), (8, vec![256]), (7, vec![128])]
+        );
+        assert_eq!(buddy.bloque_de(1).map(|(_, a)| a.tamaño()), Some(128));
+        assert_eq!(buddy.fragmentacion_interna(), 28);
+        // Los bloques por debajo del mínimo ocupan 64 KB
+        assert_eq!(buddy.asignar(2, 10), Ok(128));
+        assert_eq!(buddy.bloque_de(2).map(|(_, a)| a.orden), Some(6));
+    }
+
+    #[test]
+    fn liberar_fusiona_hasta_una_sola_raiz() {
+        let mut buddy = AsignadorBuddy::new(1024, 64);
+        for (pid, tamaño) in [(1, 100), (2, 64), (3, 200), (4, 64)] {
+            buddy.asignar(pid, tamaño).unwrap();
+        }
+        for pid in [3, 1, 4, 2] {
+            assert!(buddy.liberar(pid));
+        }
+        assert_eq!(libres_por_orden(&buddy), [(10, vec![0])]);
+        assert_eq!(buddy.arbol(), [(0, 0, 10, NodoBuddy::Libre)]);
+        assert!(!buddy.liberar(1));
+    }
+
+    #[test]
+    fn no_fusiona_si_el_compañero_esta_ocupado() {
+        let mut buddy = AsignadorBuddy::new(256, 64);
+        for pid in 1..=4 {
+            buddy.asignar(pid, 64).unwrap();
+        }
+        buddy.liberar(2);
+        buddy.liberar(3);
+        // 64 y 128 están libres pero no son compañeros
+        assert_eq!(libres_por_orden(&buddy), [(6, vec![64, 128])]);
+        assert_eq!(buddy.mayor_bloque_libre(), 64);
+        assert!(buddy.asignar(5, 128).is_err());
+        assert_eq!(buddy.asignaciones_fallidas, 1);
+    }
+
+    #[test]
+    fn la_raiz_es_la_mayor_potencia_de_dos() {
+        let buddy = AsignadorBuddy::new(1000, 64);
+        assert_eq!((buddy.tamaño_kb, buddy.orden_maximo), (512, 9));
+        assert_eq!(buddy.libre_total(), 512);
+    }
+}
@@ -0,0 +1,311 @@
+use crate::buddy::{AsignadorBuddy, BLOQUE_MINIMO_BUDDY};
+use crate::contigua::EstrategiaAjuste;
+use crate::error::ErrorMemoria;
+use crate::models::{
+    AlcanceReemplazo, AlgoritmoReemplazo, AsignacionInicial, GestorMemoria, ModoMemoria,
+    ParametrosPff, ParametrosPoliticas,
+};
+use crate::slab::{caches_por_defecto, AsignadorSlab};
+use crate::tablas::{OrganizacionTabla, ParametrosTablaPaginas, TablaHash, MAX_NIVELES};
+use crate::tlb::{LatenciasAcceso, ModoTlb, ParametrosTlb, ReemplazoTlb};
+use std::fs;
+
+/// Configuración del sistema leída desde config.ini (`CLAVE=valor`, `#` comenta)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Configuracion {
+    pub tamaño_ram: usize,                     // RAM_SIZE en KB
+    pub tamaño_swap: usize,                    // SWAP_SIZE en KB
+    pub tamaño_pagina: usize,                  // PAGE_SIZE en KB
+    pub algoritmo: AlgoritmoReemplazo,         // Algoritmo por defecto (clave ALGORITHM)
+    pub parametros: ParametrosPoliticas,       // LFU_DECAY, AGING_BITS, WS_WINDOW, RANDOM_SEED
+    pub alcance: AlcanceReemplazo,             // REPLACEMENT_SCOPE
+    pub asignacion: AsignacionInicial,         // FRAME_ALLOCATION
+    pub pff: Option<ParametrosPff>,            // PFF_ENABLED, PFF_UPPER, PFF_LOWER, PFF_WINDOW
+    pub tlb: ParametrosTlb, // TLB_SIZE, TLB_WAYS, TLB_REPLACEMENT, TLB_MODE, RANDOM_SEED
+    pub latencias: LatenciasAcceso, // MEM_LATENCY_NS, TLB_LATENCY_NS, FAULT_LATENCY_NS
+    pub tabla_paginas: ParametrosTablaPaginas, // PAGE_TABLE_BITS, PTE_SIZE
+    pub organizacion: OrganizacionTabla, // PAGE_TABLE_TYPE
+    pub cubetas_hash: usize, // HASH_TABLE_SIZE (0 = una por marco)
+    pub modo: ModoMemoria,  // MEMORY_MODE
+    pub estrategia: EstrategiaAjuste, // FIT_STRATEGY (modo contiguo)
+    pub bloque_minimo_buddy: usize, // BUDDY_MIN_BLOCK en KB (modo buddy)
+    pub caches_slab: Vec<(String, usize)>, // SLAB_CACHES: nombre y bytes de cada objeto
+}
+
+impl Configuracion {
+    /// Lee y valida el archivo de configuración
+    pub fn cargar(ruta: &str) -> Result<Self, ErrorMemoria> {
+        let contenido = fs::read_to_string(ruta).map_err(|_| {
+            ErrorMemoria::ConfiguracionInvalida(format!("archivo {} no encontrado", ruta))
+        })?;
+        Self::desde_texto(&contenido)
+    }
+
+    /// Interpreta y valida el contenido de un config.ini
+    pub fn desde_texto(contenido: &str) -> Result<Self, ErrorMemoria> {
+        let invalida = |detalle: String| ErrorMemoria::ConfiguracionInvalida(detalle);
+
+        let mut ram = 0;
+        let mut swap = 0;
+        let mut pagina = 0;
+        let mut algoritmo = AlgoritmoReemplazo::FIFO;
+        let mut parametros = ParametrosPoliticas::default();
+        let mut alcance = AlcanceReemplazo::Global;
+        let mut asignacion = AsignacionInicial::Equitativa;
+        let mut pff = ParametrosPff::default();
+        let mut pff_activo = false;
+        let mut tlb = ParametrosTlb::default();
+        let mut latencias = LatenciasAcceso::default();
+        let mut tabla_paginas = ParametrosTablaPaginas::default();
+        let mut organizacion = OrganizacionTabla::Jerarquica;
+        let mut cubetas_hash = 0;
+        let mut modo = ModoMemoria::Paginacion;
+        let mut estrategia = EstrategiaAjuste::PrimerAjuste;
+        let mut bloque_minimo_buddy = BLOQUE_MINIMO_BUDDY;
+        let mut caches_slab: Option<Vec<(String, usize)>> = None;
+
+        for linea in contenido.lines() {
+            let linea = linea.split('#').next().unwrap_or("");
+            let Some((clave, valor)) = linea.split_once('=') else {
+                continue;
+            };
+            let (clave, valor) = (clave.trim(), valor.trim());
+            let numero = || {
+                valor
+                    .parse::<usize>()
+                    .map_err(|_| invalida(format!("{} = '{}' no es un número", clave, valor)))
+            };
+            let fuera_de_rango =
+                || invalida(format!("{} = '{}' está fuera de rango", clave, valor));
+            let numero_u32 =
+                || numero().and_then(|n| u32::try_from(n).map_err(|_| fuera_de_rango()));
+            let numero_u64 =
+                || numero().and_then(|n| u64::try_from(n).map_err(|_| fuera_de_rango()));
+            match clave {
+                "RAM_SIZE" => ram = numero()?,
+                "SWAP_SIZE" => swap = numero()?,
+                "PAGE_SIZE" => pagina = numero()?,
+                "LFU_DECAY" => parametros.intervalo_decaimiento = numero()?,
+                "AGING_BITS" => parametros.bits_envejecimiento = numero_u32()?,
+                "WS_WINDOW" => parametros.ventana_ws = numero_u64()?,
+                "RANDOM_SEED" => {
+                    parametros.semilla_aleatoria = numero_u64()?;
+                    tlb.semilla = parametros.semilla_aleatoria;
+                }
+                "PFF_ENABLED" => pff_activo = numero()? != 0,
+                "PFF_UPPER" => pff.umbral_superior = numero()? as f64 / 100.0,
+                "PFF_LOWER" => pff.umbral_inferior = numero()? as f64 / 100.0,
+                "PFF_WINDOW" => pff.ventana = numero()?,
+                "TLB_SIZE" => tlb.entradas = numero()?,
+                "TLB_WAYS" => tlb.vias = numero()?,
+                "MEM_LATENCY_NS" => latencias.memoria_ns = numero()? as f64,
+                "TLB_LATENCY_NS" => latencias.tlb_ns = numero()? as f64,
+                "FAULT_LATENCY_NS" => latencias.fallo_ns = numero()? as f64,
+                "PTE_SIZE" => tabla_paginas.tamaño_entrada = numero()?,
+                "HASH_TABLE_SIZE" => cubetas_hash = numero()?,
+                "BUDDY_MIN_BLOCK" => bloque_minimo_buddy = numero()?,
+                "MEMORY_MODE" => {
+                    modo = ModoMemoria::desde_nombre(valor).ok_or_else(|| {
+                        invalida("MEMORY_MODE debe ser Paginacion, Contigua o Buddy".to_string())
+                    })?
+                }
+                "FIT_STRATEGY" => {
+                    estrategia = EstrategiaAjuste::desde_nombre(valor).ok_or_else(|| {
+                        invalida(
+                            "FIT_STRATEGY debe ser First-fit, Best-fit, Worst-fit o Next-fit"
+                                .to_string(),
+                        )
+                    })?
+                }
+                "PAGE_TABLE_TYPE" => {
+                    organizacion = OrganizacionTabla::desde_nombre(valor).ok_or_else(|| {
+                        invalida(
+                            "PAGE_TABLE_TYPE debe ser Jerarquica, Invertida o Hash".to_string(),
+                        )
+                    })?
+                }
+                "SLAB_CACHES" => {
+                    caches_slab = valor
+                        .split(',')
+                        .map(|cache| {
+                            let (nombre, tamaño) = cache.split_once(':')?;
+                            let tamaño = tamaño.trim().parse::<usize>().ok()?;
+                            (!nombre.trim().is_empty() && tamaño > 0)
+                                .then(|| (nombre.trim().to_string(), tamaño))
+                        })
+                        .collect::<Option<_>>();
+                    if caches_slab.is_none() {
+                        return Err(invalida(format!(
+                            "SLAB_CACHES = '{}' debe ser una lista nombre:bytes (ej. inode:600)",
+                            valor
+                        )));
+                    }
+                }
+                "PAGE_TABLE_BITS" => {
+                    tabla_paginas.bits_por_nivel = valor
+                        .split(',')
+                        .map(|bits| bits.trim().parse::<u32>())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| {
+                            invalida(format!(
+                                "PAGE_TABLE_BITS = '{}' debe ser una lista de bits (ej. 10,10)",
+                                valor
+                            ))
+                        })?
+                }
+                "ALGORITHM" => {
+                    algoritmo = AlgoritmoReemplazo::desde_nombre(valor)
+                        .ok_or_else(|| invalida(format!("algoritmo '{}' desconocido", valor)))?
+                }
+                "REPLACEMENT_SCOPE" => {
+                    alcance = AlcanceReemplazo::desde_nombre(valor).ok_or_else(|| {
+                        invalida("REPLACEMENT_SCOPE debe ser Global, Local o Cuota".to_string())
+                    })?
+                }
+                "FRAME_ALLOCATION" => {
+                    asignacion = AsignacionInicial::desde_nombre(valor).ok_or_else(|| {
+                        invalida(
+                            "FRAME_ALLOCATION debe ser Equitativa, Proporcional o Prioridad"
+                                .to_string(),
+                        )
+                    })?
+                }
+                "TLB_REPLACEMENT" => {
+                    tlb.reemplazo = ReemplazoTlb::desde_nombre(valor).ok_or_else(|| {
+                        invalida("TLB_REPLACEMENT debe ser FIFO, LRU o Aleatorio".to_string())
+                    })?
+                }
+                "TLB_MODE" => {
+                    tlb.modo = ModoTlb::desde_nombre(valor)
+                        .ok_or_else(|| invalida("TLB_MODE debe ser Vaciado o ASID".to_string()))?
+                }
+                _ => {}
+            }
+        }
+
+        if ram == 0 || pagina == 0 {
+            return Err(invalida(
+                "RAM_SIZE y PAGE_SIZE deben ser mayores que cero".to_string(),
+            ));
+        }
+        if ram < pagina {
+            return Err(ErrorMemoria::SinMarcos);
+        }
+        if !(1..=64).contains(&parametros.bits_envejecimiento) {
+            return Err(invalida("AGING_BITS debe estar entre 1 y 64".to_string()));
+        }
+        if pff.umbral_inferior > pff.umbral_superior || pff.umbral_superior > 1.0 {
+            return Err(invalida(
+                "se requiere PFF_LOWER <= PFF_UPPER <= 100".to_string(),
+            ));
+        }
+        if pff.ventana == 0 {
+            return Err(invalida("PFF_WINDOW debe ser mayor que cero".to_string()));
+        }
+
+        if tlb.entradas > 0 && tlb.vias > tlb.entradas {
+            return Err(invalida(
+                "TLB_WAYS no puede ser mayor que TLB_SIZE".to_string(),
+            ));
+        }
+        if tlb.vias > 0 && tlb.entradas % tlb.vias != 0 {
+            return Err(invalida(
+                "TLB_SIZE debe ser múltiplo de TLB_WAYS".to_string(),
+            ));
+        }
+
+        let niveles = tabla_paginas.niveles();
+        if !(1..=MAX_NIVELES).contains(&niveles)
+            || tabla_paginas.bits_por_nivel.contains(&0)
+            || tabla_paginas.bits_totales() > 32
+        {
+            return Err(invalida(format!(
+                "PAGE_TABLE_BITS admite de 1 a {} niveles, cada uno de 1 bit o más y 32 en total",
+                MAX_NIVELES
+            )));
+        }
+        if tabla_paginas.tamaño_entrada == 0 {
+            return Err(invalida("PTE_SIZE debe ser mayor que cero".to_string()));
+        }
+        // Las cachés de SLAB_CACHES tienen que caber en un marco; de las
+        // cachés por defecto se descartan las que no caben
+        let caches_slab = match caches_slab {
+            Some(caches) => {
+                if let Some((nombre, tamaño)) = caches.iter().find(|(_, t)| *t > pagina * 1024) {
+                    return Err(ErrorMemoria::ObjetoDemasiadoGrande {
+                        cache: nombre.clone(),
+                        tamaño: *tamaño,
+                        marco: pagina * 1024,
+                    });
+                }
+                caches
+            }
+            None => caches_por_defecto(pagina * 1024)
+                .into_iter()
+                .map(|(nombre, tamaño)| (nombre.to_string(), tamaño))
+                .collect(),
+        };
+        if !bloque_minimo_buddy.is_power_of_two() {
+            return Err(invalida(
+                "BUDDY_MIN_BLOCK debe ser una potencia de dos".to_string(),
+            ));
+        }
+
+        Ok(Configuracion {
+            tamaño_ram: ram,
+            tamaño_swap: swap,
+            tamaño_pagina: pagina,
+            algoritmo,
+            parametros,
+            alcance,
+            asignacion,
+            pff: pff_activo.then_some(pff),
+            tlb,
+            latencias,
+            tabla_paginas,
+            organizacion,
+            cubetas_hash,
+            modo,
+            estrategia,
+            bloque_minimo_buddy,
+            caches_slab,
+        })
+    }
+
+    /// Construye un gestor con esta configuración y el algoritmo indicado
+    /// (el de `ALGORITHM` o el que se haya elegido en el menú)
+    pub fn crear_gestor(&self, algoritmo: AlgoritmoReemplazo) -> GestorMemoria {
+        let mut gestor = GestorMemoria::new(
+            self.tamaño_ram,
+            self.tamaño_swap,
+            self.tamaño_pagina,
+            algoritmo,
+        );
+        gestor.establecer_parametros(self.parametros);
+        if (self.alcance, self.asignacion) != (gestor.alcance, gestor.asignacion) {
+            gestor.establecer_alcance(self.alcance, self.asignacion);
+        }
+        if self.pff.is_some() {
+            gestor.establecer_pff(self.pff);
+        }
+        if self.tlb != gestor.tlb.parametros {
+            gestor.establecer_tlb(self.tlb);
+        }
+        gestor.latencias = self.latencias;
+        gestor.tabla_paginas = self.tabla_paginas.clone();
+        gestor.organizacion = self.organizacion;
+        gestor.modo = self.modo;
+        gestor.contigua.estrategia = self.estrategia;
+        gestor.buddy = AsignadorBuddy::new(gestor.contigua.tamaño_kb, self.bloque_minimo_buddy);
+        let caches: Vec<(&str, usize)> = self
+            .caches_slab
+            .iter()
+            .map(|(nombre, tamaño)| (nombre.as_str(), *tamaño))
+            .collect();
+        gestor.slab = AsignadorSlab::new(self.tamaño_pagina * 1024, &caches);
+        if self.cubetas_hash > 0 {
+            gestor.tabla_hash = TablaHash::new(self.cubetas_hash);
+        }
+        gestor
+    }
+}
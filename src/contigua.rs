@@ -0,0 +1,333 @@
+use crate::error::ErrorMemoria;
+
+// --- ASIGNACIÓN CONTIGUA (PARTICIONES VARIABLES) ---
+
+/// Criterio para elegir el hueco donde se coloca un proceso
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EstrategiaAjuste {
+    PrimerAjuste,    // Primer hueco (desde el inicio) donde cabe
+    MejorAjuste,     // Hueco más pequeño donde cabe
+    PeorAjuste,      // Hueco más grande
+    SiguienteAjuste, // Primer hueco donde cabe a partir de la última asignación
+}
+
+impl EstrategiaAjuste {
+    pub const TODOS: [EstrategiaAjuste; 4] = [
+        EstrategiaAjuste::PrimerAjuste,
+        EstrategiaAjuste::MejorAjuste,
+        EstrategiaAjuste::PeorAjuste,
+        EstrategiaAjuste::SiguienteAjuste,
+    ];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            EstrategiaAjuste::PrimerAjuste => "First-fit",
+            EstrategiaAjuste::MejorAjuste => "Best-fit",
+            EstrategiaAjuste::PeorAjuste => "Worst-fit",
+            EstrategiaAjuste::SiguienteAjuste => "Next-fit",
+        }
+    }
+
+    /// Busca una estrategia por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|e| e.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+}
+
+/// Tramo de memoria: un proceso o un hueco libre
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Bloque {
+    pub inicio: usize,      // Dirección de inicio en KB
+    pub tamaño: usize,      // Tamaño en KB
+    pub pid: Option<usize>, // Proceso que lo ocupa (None = hueco)
+}
+
+impl Bloque {
+    pub fn fin(&self) -> usize {
+        self.inicio + self.tamaño
+    }
+}
+
+/// Memoria con particiones variables: cada proceso ocupa un único bloque
+/// contiguo de `tamaño_kb`. Los bloques están ordenados por dirección, cubren
+/// toda la memoria y dos huecos nunca quedan adyacentes (se fusionan).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoriaContigua {
+    pub tamaño_kb: usize,
+    pub bloques: Vec<Bloque>,
+    pub estrategia: EstrategiaAjuste,
+    cursor: usize, // Next-fit: dirección donde terminó la última asignación
+    pub asignaciones_fallidas: usize,
+    pub compactaciones: usize, // Compactaciones realizadas
+    pub kb_movidos: usize,     // KB copiados sumando todas las compactaciones
+}
+
+impl MemoriaContigua {
+    pub fn new(tamaño_kb: usize, estrategia: EstrategiaAjuste) -> Self {
+        let bloques = if tamaño_kb == 0 {
+            Vec::new()
+        } else {
+            vec![Bloque {
+                inicio: 0,
+                tamaño: tamaño_kb,
+                pid: None,
+            }]
+        };
+        Self {
+            tamaño_kb,
+            bloques,
+            estrategia,
+            cursor: 0,
+            asignaciones_fallidas: 0,
+            compactaciones: 0,
+            kb_movidos: 0,
+        }
+    }
+
+    /// Coloca un bloque de `tamaño` KB para el proceso según la estrategia.
+    /// Devuelve la dirección de inicio, `BloqueVacio` si `tamaño` es 0 o
+    /// `SinHueco` si ningún hueco basta.
+    pub fn asignar(&mut self, pid: usize, tamaño: usize) -> Result<usize, ErrorMemoria> {
+        // Un bloque de 0 KB partiría un hueco sin ocupar nada
+        if tamaño == 0 {
+            return Err(ErrorMemoria::BloqueVacio);
+        }
+        let candidatos = self
+            .bloques
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.pid.is_none() && b.tamaño >= tamaño);
+        let elegido = match self.estrategia {
+            EstrategiaAjuste::PrimerAjuste => candidatos.map(|(i, _)| i).next(),
+            EstrategiaAjuste::MejorAjuste => {
+                candidatos.min_by_key(|(_, b)| b.tamaño).map(|(i, _)| i)
+            }
+            EstrategiaAjuste::PeorAjuste => {
+                candidatos.max_by_key(|(_, b)| b.tamaño).map(|(i, _)| i)
+            }
+            EstrategiaAjuste::SiguienteAjuste => {
+                // El primero a partir del cursor; si no hay, se da la vuelta
+                let cursor = self.cursor;
+                candidatos
+                    .min_by_key(|(_, b)| (b.fin() <= cursor, b.inicio))
+                    .map(|(i, _)| i)
+            }
+        };
+
+        let Some(idx) = elegido else {
+            self.asignaciones_fallidas += 1;
+            return Err(ErrorMemoria::SinHueco {
+                solicitado: tamaño,
+                mayor_hueco: self.mayor_hueco(),
+                libre: self.libre_total(),
+            });
+        };
+
+        let hueco = self.bloques[idx];
+        self.bloques[idx] = Bloque {
+            inicio: hueco.inicio,
+            tamaño,
+            pid: Some(pid),
+        };
+        if hueco.tamaño > tamaño {
+            self.bloques.insert(
+                idx + 1,
+                Bloque {
+                    inicio: hueco.inicio + tamaño,
+                    tamaño: hueco.tamaño - tamaño,
+                    pid: None,
+                },
+            );
+        }
+        self.cursor = hueco.inicio + tamaño;
+        Ok(hueco.inicio)
+    }
+
+    /// Libera el bloque del proceso y lo fusiona con los huecos vecinos.
+    /// Devuelve si el proceso tenía bloque.
+    pub fn liberar(&mut self, pid: usize) -> bool {
+        let Some(idx) = self.bloques.iter().position(|b| b.pid == Some(pid)) else {
+            return false;
+        };
+        self.bloques[idx].pid = None;
+
+        let mut idx = idx;
+        if idx + 1 < self.bloques.len() && self.bloques[idx + 1].pid.is_none() {
+            let siguiente = self.bloques.remove(idx + 1);
+            self.bloques[idx].tamaño += siguiente.tamaño;
+        }
+        if idx > 0 && self.bloques[idx - 1].pid.is_none() {
+            let actual = self.bloques.remove(idx);
+            idx -= 1;
+            self.bloques[idx].tamaño += actual.tamaño;
+        }
+        true
+    }
+
+    /// Desliza todos los procesos hacia el inicio de la memoria, en su orden,
+    /// dejando un único hueco al final. Devuelve los KB movidos (el tamaño de
+    /// cada bloque que cambia de dirección) y los bloques reubicados.
+    pub fn compactar(&mut self) -> (usize, usize) {
+        let mut destino = 0;
+        let mut movidos = 0;
+        let mut reubicados = 0;
+        let mut bloques: Vec<Bloque> = Vec::with_capacity(self.bloques.len());
+        for bloque in self.bloques.iter().filter(|b| b.pid.is_some()) {
+            if bloque.inicio != destino {
+                movidos += bloque.tamaño;
+                reubicados += 1;
+            }
+            bloques.push(Bloque {
+                inicio: destino,
+                ..*bloque
+            });
+            destino += bloque.tamaño;
+        }
+        if destino < self.tamaño_kb {
+            bloques.push(Bloque {
+                inicio: destino,
+                tamaño: self.tamaño_kb - destino,
+                pid: None,
+            });
+        }
+
+        self.bloques = bloques;
+        self.cursor = destino;
+        self.compactaciones += 1;
+        self.kb_movidos += movidos;
+        (movidos, reubicados)
+    }
+
+    /// Bloque que ocupa el proceso
+    pub fn bloque_de(&self, pid: usize) -> Option<&Bloque> {
+        self.bloques.iter().find(|b| b.pid == Some(pid))
+    }
+
+    /// Bloque (proceso o hueco) que contiene la dirección en KB
+    pub fn bloque_en(&self, direccion_kb: usize) -> Option<&Bloque> {
+        self.bloques
+            .iter()
+            .find(|b| b.inicio <= direccion_kb && direccion_kb < b.fin())
+    }
+
+    pub fn huecos(&self) -> impl Iterator<Item = &Bloque> {
+        self.bloques.iter().filter(|b| b.pid.is_none())
+    }
+
+    /// KB libres sumando todos los huecos
+    pub fn libre_total(&self) -> usize {
+        self.huecos().map(|b| b.tamaño).sum()
+    }
+
+    pub fn mayor_hueco(&self) -> usize {
+        self.huecos().map(|b| b.tamaño).max().unwrap_or(0)
+    }
+
+    /// Fragmentación externa: porcentaje de la memoria libre que no está en
+    /// el mayor hueco (0% = toda la memoria libre es un único hueco)
+    pub fn fragmentacion_externa(&self) -> f64 {
+        let libre = self.libre_total();
+        if libre == 0 {
+            return 0.0;
+        }
+        (1.0 - self.mayor_hueco() as f64 / libre as f64) * 100.0
+    }
+
+    /// Porcentaje de la memoria ocupada por procesos
+    pub fn utilizacion(&self) -> f64 {
+        if self.tamaño_kb == 0 {
+            return 0.0;
+        }
+        ((self.tamaño_kb - self.libre_total()) as f64 / self.tamaño_kb as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 400 KB con huecos de 50 KB en 100, 20 KB en 180 y 100 KB en 300
+    fn memoria_con_huecos(estrategia: EstrategiaAjuste) -> MemoriaContigua {
+        let mut memoria = MemoriaContigua::new(400, estrategia);
+        for (pid, tamaño) in [(1, 100), (2, 50), (3, 30), (4, 20), (5, 100)] {
+            memoria.asignar(pid, tamaño).unwrap();
+        }
+        memoria.liberar(2);
+        memoria.liberar(4);
+        memoria
+    }
+
+    fn inicios_huecos(memoria: &MemoriaContigua) -> Vec<(usize, usize)> {
+        memoria.huecos().map(|b| (b.inicio, b.tamaño)).collect()
+    }
+
+    #[test]
+    fn cada_estrategia_elige_su_hueco() {
+        let casos = [
+            (EstrategiaAjuste::PrimerAjuste, 100),
+            (EstrategiaAjuste::MejorAjuste, 180),
+            (EstrategiaAjuste::PeorAjuste, 300),
+            (EstrategiaAjuste::SiguienteAjuste, 300),
+        ];
+        for (estrategia, esperado) in casos {
+            let mut memoria = memoria_con_huecos(estrategia);
+            assert_eq!(memoria.asignar(9, 20), Ok(esperado), "{:?}", estrategia);
+        }
+    }
+
+    #[test]
+    fn siguiente_ajuste_da_la_vuelta() {
+        let mut memoria = memoria_con_huecos(EstrategiaAjuste::SiguienteAjuste);
+        assert_eq!(memoria.asignar(6, 10), Ok(300));
+        assert_eq!(memoria.asignar(7, 60), Ok(310));
+        // Tras el cursor solo quedan 30 KB: vuelve al principio
+        assert_eq!(memoria.asignar(8, 50), Ok(100));
+    }
+
+    #[test]
+    fn sin_hueco_suficiente() {
+        let mut memoria = memoria_con_huecos(EstrategiaAjuste::PrimerAjuste);
+        assert_eq!(
+            memoria.asignar(9, 150),
+            Err(ErrorMemoria::SinHueco {
+                solicitado: 150,
+                mayor_hueco: 100,
+                libre: 170,
+            })
+        );
+        assert_eq!(memoria.asignaciones_fallidas, 1);
+    }
+
+    #[test]
+    fn bloque_de_cero_kb() {
+        let mut memoria = memoria_con_huecos(EstrategiaAjuste::PrimerAjuste);
+        let bloques = memoria.bloques.len();
+        assert_eq!(memoria.asignar(9, 0), Err(ErrorMemoria::BloqueVacio));
+        assert_eq!(memoria.bloques.len(), bloques);
+        assert_eq!(memoria.asignaciones_fallidas, 0);
+    }
+
+    #[test]
+    fn liberar_fusiona_con_ambos_vecinos() {
+        let mut memoria = memoria_con_huecos(EstrategiaAjuste::PrimerAjuste);
+        assert_eq!(inicios_huecos(&memoria), [(100, 50), (180, 20), (300, 100)]);
+        assert!(memoria.liberar(3));
+        assert_eq!(inicios_huecos(&memoria), [(100, 100), (300, 100)]);
+        assert!(memoria.liberar(5));
+        assert_eq!(inicios_huecos(&memoria), [(100, 300)]);
+        assert!(!memoria.liberar(5));
+    }
+
+    #[test]
+    fn compactar_cuenta_los_kb_movidos() {
+        let mut memoria = memoria_con_huecos(EstrategiaAjuste::PrimerAjuste);
+        // P3 (30 KB) baja de 150 a 100 y P5 (100 KB) de 200 a 130
+        assert_eq!(memoria.compactar(), (130, 2));
+        assert_eq!(inicios_huecos(&memoria), [(230, 170)]);
+        assert_eq!(memoria.compactar(), (0, 0));
+        assert_eq!((memoria.compactaciones, memoria.kb_movidos), (2, 130));
+        assert_eq!(memoria.fragmentacion_externa(), 0.0);
+    }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+/// Errores del motor de memoria. Cada variante identifica un fallo concreto
+/// para que quien use la biblioteca pueda reaccionar sin analizar mensajes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorMemoria {
+    SwapLleno {
+        capacidad: usize, // Páginas que caben en Swap
+    },
+    SwapInsuficiente {
+        desalojos: usize, // Páginas que habría que enviar a Swap para admitir el proceso
+        libres: usize,    // Huecos libres en Swap
+    },
+    SinMarcos,             // La RAM no tiene ningún marco (RAM_SIZE < PAGE_SIZE)
+    PidDesconocido(usize), // No hay ningún proceso activo con ese PID
+    PidDuplicado(usize),   // Ya hay un proceso activo con ese PID
+    PaginaFueraDeRango {
+        pid: usize,
+        pagina: usize,
+    },
+    LimiteProcesos {
+        maximo: usize, // Procesos activos permitidos
+    },
+    EspacioVirtualInsuficiente {
+        paginas: usize, // Páginas que necesita el proceso
+        maximo: usize,  // Páginas direccionables con los bits de la tabla
+    },
+    SinHueco {
+        solicitado: usize,  // KB que pide el proceso
+        mayor_hueco: usize, // KB del mayor hueco libre
+        libre: usize,       // KB libres sumando todos los huecos
+    },
+    BloqueVacio, // Se pidió un bloque contiguo de 0 KB
+    FueraDeLimite {
+        pid: usize,
+        direccion: usize, // Dirección virtual en bytes
+        limite: usize,    // Tamaño del proceso (o de su bloque) en bytes
+    },
+    CacheDesconocida(String), // No hay ninguna caché slab con ese nombre
+    ObjetoDemasiadoGrande {
+        cache: String,
+        tamaño: usize, // Bytes del objeto
+        marco: usize,  // Bytes de un marco
+    },
+    ModoIncompatible(&'static str), // La operación no existe en el modo de memoria activo
+    ConfiguracionInvalida(String),  // Clave o valor de config.ini no válido
+    PoliticaDesconocida(String),    // Nombre de política no registrado
+    VictimaInvalida {
+        politica: String, // La política devolvió un marco libre o inexistente
+    },
+}
+
+/// Motivo por el que `asignar_proceso` rechaza un proceso. Se conserva como
+/// alias: los rechazos de admisión son variantes de [`ErrorMemoria`]
+/// (`PidDuplicado`, `SinMarcos`, `SwapInsuficiente`, ...) y un fallo del
+/// reemplazo durante la carga llega con su propio error (`SwapLleno`,
+/// `VictimaInvalida`).
+pub type RechazoAdmision = ErrorMemoria;
+
+impl fmt::Display for ErrorMemoria {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorMemoria::SwapLleno { capacidad } => {
+                write!(f, "Swap lleno ({} páginas MAX)", capacidad)
+            }
+            ErrorMemoria::SwapInsuficiente { desalojos, libres } => write!(
+                f,
+                "Swap insuficiente: requiere {} desalojos y hay {} huecos libres",
+                desalojos, libres
+            ),
+            ErrorMemoria::SinMarcos => write!(f, "La RAM no tiene marcos"),
+            ErrorMemoria::PidDesconocido(pid) => write!(f, "Proceso P{} no existe", pid),
+            ErrorMemoria::PidDuplicado(pid) => write!(f, "PID {} ya está en uso", pid),
+            ErrorMemoria::PaginaFueraDeRango { pid, pagina } => {
+                write!(f, "Página {} fuera de rango para P{}", pagina, pid)
+            }
+            ErrorMemoria::LimiteProcesos { maximo } => {
+                write!(f, "Límite máximo de procesos ({}) alcanzado", maximo)
+            }
+            ErrorMemoria::EspacioVirtualInsuficiente { paginas, maximo } => write!(
+                f,
+                "El proceso necesita {} páginas y el espacio virtual admite {}",
+                paginas, maximo
+            ),
+            ErrorMemoria::SinHueco {
+                solicitado,
+                mayor_hueco,
+                libre,
+            } => write!(
+                f,
+                "Sin hueco de {}KB (mayor hueco {}KB, {}KB libres en total)",
+                solicitado, mayor_hueco, libre
+            ),
+            ErrorMemoria::BloqueVacio => write!(f, "No se puede asignar un bloque de 0KB"),
+            ErrorMemoria::FueraDeLimite {
+                pid,
+                direccion,
+                limite,
+            } => write!(
+                f,
+                "Dirección 0x{:X} fuera del límite de P{} ({} bytes)",
+                direccion, pid, limite
+            ),
+            ErrorMemoria::CacheDesconocida(nombre) => {
+                write!(f, "Caché slab '{}' no existe", nombre)
+            }
+            ErrorMemoria::ObjetoDemasiadoGrande {
+                cache,
+                tamaño,
+                marco,
+            } => write!(
+                f,
+                "Los objetos de {} ({} bytes) no caben en un marco de {} bytes",
+                cache, tamaño, marco
+            ),
+            ErrorMemoria::ModoIncompatible(modo) => {
+                write!(f, "Operación no disponible en modo {}", modo)
+            }
+            ErrorMemoria::ConfiguracionInvalida(detalle) => {
+                write!(f, "Configuración inválida: {}", detalle)
+            }
+            ErrorMemoria::PoliticaDesconocida(nombre) => {
+                write!(f, "Política '{}' no registrada", nombre)
+            }
+            ErrorMemoria::VictimaInvalida { politica } => {
+                write!(f, "La política {} no eligió una víctima válida", politica)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ErrorMemoria {}
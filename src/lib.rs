@@ -0,0 +1,34 @@
+//! Núcleo del simulador de gestor de memoria (paginación + Swap, particiones variables o buddy).
+//!
+//! La interfaz TUI (`main.rs` + `ui.rs`) es solo un cliente de esta biblioteca;
+//! cualquier herramienta externa puede construir un [`GestorMemoria`], crear y
+//! terminar procesos, acceder a páginas y consultar métricas.
+
+pub mod buddy;
+pub mod config;
+pub mod contigua;
+pub mod error;
+pub mod models;
+pub mod politicas;
+pub mod slab;
+pub mod tablas;
+pub mod tlb;
+
+pub use buddy::{AsignacionBuddy, AsignadorBuddy, NodoBuddy, BLOQUE_MINIMO_BUDDY};
+pub use config::Configuracion;
+pub use contigua::{Bloque, EstrategiaAjuste, MemoriaContigua};
+pub use error::{ErrorMemoria, RechazoAdmision};
+pub use models::{
+    AlcanceReemplazo, AlgoritmoReemplazo, AsignacionInicial, GestorMemoria, Marco, Metricas,
+    ModoMemoria, Pagina, ParametrosPff, ParametrosPoliticas, Proceso, TipoAcceso, Traduccion,
+    MAX_PROCESOS, VENTANA_OPTIMO,
+};
+pub use politicas::{fallos_optimos, FabricaPolitica, PoliticaReemplazo, VistaMemoria};
+pub use slab::{
+    caches_por_defecto, AsignadorSlab, CacheSlab, EstadoSlab, Slab, CACHES_POR_DEFECTO,
+};
+pub use tablas::{
+    CosteBusqueda, OrganizacionTabla, ParametrosTablaPaginas, TablaHash, TablaInvertida,
+    MAX_NIVELES,
+};
+pub use tlb::{EntradaTlb, LatenciasAcceso, ModoTlb, ParametrosTlb, ReemplazoTlb, Tlb};
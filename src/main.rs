@@ -1,4 +1,3 @@
-mod models;
 mod ui;
 
 use crossterm::{
@@ -6,58 +5,22 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use models::{AlgoritmoReemplazo, GestorMemoria, Proceso};
+use memory_sim::{
+    AlcanceReemplazo, AlgoritmoReemplazo, Configuracion, ErrorMemoria, EstrategiaAjuste,
+    GestorMemoria, ModoMemoria, OrganizacionTabla, ParametrosTablaPaginas, Proceso, MAX_NIVELES,
+};
 use rand::Rng;
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::{
-    fs, io,
+    io,
     time::{Duration, Instant},
 };
 
-const MAX_PROCESOS: usize = 30;
-
-/// Configuración del sistema leída desde config.ini
-struct Configuracion {
-    tamaño_ram: usize,
-    tamaño_swap: usize,
-    tamaño_pagina: usize,
-}
-
-impl Configuracion {
-    fn cargar(ruta: &str) -> Result<Self, String> {
-        let contenido =
-            fs::read_to_string(ruta).map_err(|_| "ERROR: Archivo config.ini no encontrado")?;
-
-        let mut ram = 0;
-        let mut swap = 0;
-        let mut pagina = 0;
-
-        for linea in contenido.lines() {
-            if let Some((clave, valor)) = linea.split_once('=') {
-                let v = valor.trim().parse::<usize>().unwrap_or(0);
-                match clave.trim() {
-                    "RAM_SIZE" => ram = v,
-                    "SWAP_SIZE" => swap = v,
-                    "PAGE_SIZE" => pagina = v,
-                    _ => {}
-                }
-            }
-        }
-
-        if ram == 0 || pagina == 0 {
-            return Err("ERROR: Configuración inválida (valores en cero)".to_string());
-        }
-        Ok(Configuracion {
-            tamaño_ram: ram,
-            tamaño_swap: swap,
-            tamaño_pagina: pagina,
-        })
-    }
-}
+const REFERENCIAS_POR_TICK: usize = 2; // Accesos a memoria de cada proceso por tick
 
 fn main() -> Result<(), anyhow::Error> {
     // 1. Cargar configuración
-    let config = Configuracion::cargar("config.ini").expect("ERROR: Fallo al cargar config.ini");
+    let config = Configuracion::cargar("config.ini")?;
 
     // 2. Mostrar menú de selección
     println!("====================================");
@@ -65,22 +28,33 @@ fn main() -> Result<(), anyhow::Error> {
     println!("====================================");
     println!();
     println!("Seleccione Algoritmo de Reemplazo:");
-    println!("  1) FIFO (Primero en Entrar, Primero en Salir)");
-    println!("  2) Reloj (Segunda Oportunidad)");
-    println!("  3) LRU (Menos Recientemente Usado)");
+    for (i, alg) in AlgoritmoReemplazo::TODOS.iter().enumerate() {
+        println!("  {}) {}", i + 1, alg.descripcion());
+    }
     println!();
-    print!("Ingrese su elección (1-3): ");
+    print!(
+        "Ingrese su elección (1-{}, Enter = {}): ",
+        AlgoritmoReemplazo::TODOS.len(),
+        config.algoritmo.nombre()
+    );
     io::Write::flush(&mut io::stdout())?;
 
     let mut input = String::new();
     io::stdin().read_line(&mut input)?;
-    let algoritmo = match input.trim() {
-        "1" => AlgoritmoReemplazo::FIFO,
-        "2" => AlgoritmoReemplazo::Reloj,
-        "3" => AlgoritmoReemplazo::LRU,
-        _ => {
-            println!("Elección inválida, usando FIFO por defecto");
-            AlgoritmoReemplazo::FIFO
+    let eleccion = input
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| AlgoritmoReemplazo::TODOS.get(n.wrapping_sub(1)).copied());
+    let algoritmo = match eleccion {
+        _ if input.trim().is_empty() => config.algoritmo,
+        Some(alg) => alg,
+        None => {
+            println!(
+                "Elección inválida, usando {} por defecto",
+                config.algoritmo.nombre()
+            );
+            config.algoritmo
         }
     };
 
@@ -111,12 +85,7 @@ fn main() -> Result<(), anyhow::Error> {
     let mut terminal = Terminal::new(backend)?;
 
     // 4. Iniciar gestor de memoria
-    let mut gestor = GestorMemoria::new(
-        config.tamaño_ram,
-        config.tamaño_swap,
-        config.tamaño_pagina,
-        algoritmo,
-    );
+    let mut gestor = config.crear_gestor(algoritmo);
 
     // 5. Ejecutar simulador
     let resultado = ejecutar_app(&mut terminal, &mut gestor, modo_auto);
@@ -146,10 +115,11 @@ fn ejecutar_app<B: ratatui::backend::Backend>(
     let mut temporizador_accion = Instant::now();
     let mut contador_pid = 1;
     let mut pausado = false;
+    let mut dialogo = ui::DialogoTraduccion::default();
 
     loop {
         // A. Dibujar interfaz
-        terminal.draw(|f| ui::dibujar(f, gestor, pausado, modo_auto))?;
+        terminal.draw(|f| ui::dibujar(f, gestor, pausado, modo_auto, &dialogo))?;
 
         // B. Escuchar teclado
         let timeout = velocidad_tick
@@ -158,43 +128,157 @@ fn ejecutar_app<B: ratatui::backend::Backend>(
 
         if crossterm::event::poll(timeout)? {
             if let Event::Key(tecla) = event::read()? {
-                match tecla.code {
-                    KeyCode::Char('q') => return Ok(()),
-                    KeyCode::Char('p') => {
-                        pausado = !pausado;
-                        let estado = if pausado { "PAUSADO" } else { "EJECUTANDO" };
-                        gestor.registrar_log(&format!("[ESTADO] Sistema {}", estado));
-                    }
-                    KeyCode::Char('a') => {
-                        modo_auto = !modo_auto;
-                        let modo = if modo_auto { "AUTOMATICO" } else { "MANUAL" };
-                        gestor.registrar_log(&format!("[MODO] Cambiado a modo {}", modo));
+                if let Some(entrada) = dialogo.entrada.as_mut() {
+                    // Mientras se escribe la dirección, las teclas van al diálogo
+                    match tecla.code {
+                        KeyCode::Char(c) => entrada.push(c),
+                        KeyCode::Backspace => {
+                            entrada.pop();
+                        }
+                        KeyCode::Esc => dialogo.cerrar(),
+                        KeyCode::Enter => {
+                            dialogo.resultado = traducir(gestor, entrada);
+                            dialogo.entrada = None;
+                        }
+                        _ => {}
                     }
-                    KeyCode::Char('n') => {
-                        // Crear proceso manualmente (verificar límite)
-                        if gestor.procesos.len() >= MAX_PROCESOS {
-                            gestor
-                                .registrar_log("[ERROR] Límite máximo de procesos (30) alcanzado");
-                        } else {
+                } else if tecla.code == KeyCode::Esc {
+                    dialogo.cerrar();
+                } else {
+                    match tecla.code {
+                        KeyCode::Char('q') => return Ok(()),
+                        KeyCode::Char('p') => {
+                            pausado = !pausado;
+                            let estado = if pausado { "PAUSADO" } else { "EJECUTANDO" };
+                            gestor.registrar_log(&format!("[ESTADO] Sistema {}", estado));
+                        }
+                        KeyCode::Char('a') => {
+                            modo_auto = !modo_auto;
+                            let modo = if modo_auto { "AUTOMATICO" } else { "MANUAL" };
+                            gestor.registrar_log(&format!("[MODO] Cambiado a modo {}", modo));
+                        }
+                        KeyCode::Char('n') => {
+                            // Crear proceso manualmente (el gestor verifica el límite)
                             crear_proceso(gestor, &mut contador_pid);
                         }
+                        KeyCode::Char('k') => {
+                            gestor.matar_proceso_aleatorio();
+                        }
+                        KeyCode::Char('v') => {
+                            // Verifica que RAM, tablas de páginas y Swap coinciden
+                            let errores = gestor.verificar_consistencia();
+                            if errores.is_empty() {
+                                gestor.registrar_log(
+                                    "[CHECK] Tablas de páginas consistentes con la RAM y el Swap",
+                                );
+                            }
+                            for error in errores {
+                                gestor.registrar_log(&format!("[CHECK] {}", error));
+                            }
+                        }
+                        KeyCode::Char('t') => {
+                            // Abre el diálogo de traducción de direcciones
+                            dialogo.resultado.clear();
+                            dialogo.entrada = Some(String::new());
+                        }
+                        KeyCode::Char('l') => {
+                            // Alterna entre tabla de 1, 2 y 3 niveles con los mismos bits totales
+                            let tabla = &gestor.tabla_paginas;
+                            let siguiente = ParametrosTablaPaginas::repartida(
+                                tabla.bits_totales(),
+                                tabla.niveles() % MAX_NIVELES + 1,
+                                tabla.tamaño_entrada,
+                            );
+                            if let Err(error) = gestor.establecer_tabla_paginas(siguiente) {
+                                gestor.registrar_log(&format!("[ERROR] {}", error));
+                            }
+                        }
+                        KeyCode::Char('o') => {
+                            // Alterna la tabla recorrida: Jerárquica -> Invertida -> Hash
+                            let actual = OrganizacionTabla::TODOS
+                                .iter()
+                                .position(|&o| o == gestor.organizacion)
+                                .unwrap_or(0);
+                            gestor.establecer_organizacion(
+                                OrganizacionTabla::TODOS
+                                    [(actual + 1) % OrganizacionTabla::TODOS.len()],
+                            );
+                        }
+                        KeyCode::Char('m') => {
+                            // Alterna paginación / contigua / buddy (termina los procesos activos)
+                            let actual = ModoMemoria::TODOS
+                                .iter()
+                                .position(|&m| m == gestor.modo)
+                                .unwrap_or(0);
+                            gestor.establecer_modo(
+                                ModoMemoria::TODOS[(actual + 1) % ModoMemoria::TODOS.len()],
+                            );
+                        }
+                        KeyCode::Char('f') => {
+                            // Alterna la estrategia de colocación del modo contiguo
+                            let actual = EstrategiaAjuste::TODOS
+                                .iter()
+                                .position(|&e| e == gestor.contigua.estrategia)
+                                .unwrap_or(0);
+                            gestor.establecer_estrategia(
+                                EstrategiaAjuste::TODOS
+                                    [(actual + 1) % EstrategiaAjuste::TODOS.len()],
+                            );
+                        }
+                        KeyCode::Char('c') => {
+                            gestor.compactar_memoria();
+                        }
+                        KeyCode::Char('b') => {
+                            // El núcleo reserva objetos de una caché slab al azar
+                            gestor.reservar_objetos_aleatorios(16);
+                        }
+                        KeyCode::Char('d') => {
+                            gestor.liberar_objetos_aleatorios(16);
+                        }
+                        KeyCode::Char('r') => {
+                            // Devuelve a la RAM los marcos de todos los slabs vacíos
+                            let devueltos = gestor.reducir_slabs(usize::MAX);
+                            if devueltos.is_empty() {
+                                gestor.registrar_log("[SLAB] No hay slabs vacíos que devolver");
+                            }
+                        }
+                        KeyCode::Char('s') => {
+                            // Alterna el alcance del reemplazo: Global -> Local -> Cuota
+                            let actual = AlcanceReemplazo::TODOS
+                                .iter()
+                                .position(|&a| a == gestor.alcance)
+                                .unwrap_or(0);
+                            let siguiente = AlcanceReemplazo::TODOS
+                                [(actual + 1) % AlcanceReemplazo::TODOS.len()];
+                            gestor.establecer_alcance(siguiente, gestor.asignacion);
+                        }
+                        KeyCode::Tab => {
+                            // Tab recorre todas las políticas, incluidas las que no tienen tecla
+                            let nombres = gestor.politicas_registradas();
+                            let actual = nombres
+                                .iter()
+                                .position(|n| n.eq_ignore_ascii_case(gestor.nombre_algoritmo()));
+                            let siguiente = actual.map_or(0, |i| (i + 1) % nombres.len());
+                            let nombre = nombres[siguiente].to_string();
+                            if let Err(error) = gestor.seleccionar_politica(&nombre) {
+                                gestor.registrar_log(&format!("[ERROR] {}", error));
+                            }
+                        }
+                        KeyCode::Char(c @ '0'..='9') => {
+                            // La tecla N activa la N-ésima política registrada (0 = décima)
+                            let indice = (c as usize + 9 - '0' as usize) % 10;
+                            let nombre = gestor
+                                .politicas_registradas()
+                                .get(indice)
+                                .map(|n| n.to_string());
+                            if let Some(nombre) = nombre {
+                                if let Err(error) = gestor.seleccionar_politica(&nombre) {
+                                    gestor.registrar_log(&format!("[ERROR] {}", error));
+                                }
+                            }
+                        }
+                        _ => {}
                     }
-                    KeyCode::Char('k') => {
-                        gestor.matar_proceso_aleatorio();
-                    }
-                    KeyCode::Char('1') => {
-                        gestor.algoritmo = AlgoritmoReemplazo::FIFO;
-                        gestor.registrar_log("[CONFIG] Algoritmo cambiado a FIFO");
-                    }
-                    KeyCode::Char('2') => {
-                        gestor.algoritmo = AlgoritmoReemplazo::Reloj;
-                        gestor.registrar_log("[CONFIG] Algoritmo cambiado a Reloj");
-                    }
-                    KeyCode::Char('3') => {
-                        gestor.algoritmo = AlgoritmoReemplazo::LRU;
-                        gestor.registrar_log("[CONFIG] Algoritmo cambiado a LRU");
-                    }
-                    _ => {}
                 }
             }
         }
@@ -204,7 +288,7 @@ fn ejecutar_app<B: ratatui::backend::Backend>(
             let mut rng = rand::thread_rng();
             let decision = rng.gen_range(0..100);
 
-            if decision < 60 && gestor.procesos.len() < MAX_PROCESOS {
+            if decision < 60 && gestor.procesos.len() < gestor.max_procesos {
                 // 60%: Crear proceso (si no estamos en el límite)
                 crear_proceso(gestor, &mut contador_pid);
             } else if decision < 90 {
@@ -217,11 +301,36 @@ fn ejecutar_app<B: ratatui::backend::Backend>(
         }
 
         if ultimo_tick.elapsed() >= velocidad_tick {
+            // D. Los procesos en ejecución siguen referenciando memoria
+            if !pausado {
+                gestor.ejecutar_tick(REFERENCIAS_POR_TICK);
+            }
             ultimo_tick = Instant::now();
         }
     }
 }
 
+/// Interpreta "PID DIRECCION" (decimal o 0x hex) y devuelve los pasos de la traducción
+fn traducir(gestor: &mut GestorMemoria, entrada: &str) -> Vec<String> {
+    let numero = |texto: &str| match texto.strip_prefix("0x").or(texto.strip_prefix("0X")) {
+        Some(hex) => usize::from_str_radix(hex, 16).ok(),
+        None => texto.parse::<usize>().ok(),
+    };
+    let partes: Vec<&str> = entrada.split_whitespace().collect();
+    let (pid, direccion) = match partes.as_slice() {
+        [pid, direccion] => match (numero(pid), numero(direccion)) {
+            (Some(pid), Some(direccion)) => (pid, direccion),
+            _ => return vec!["Formato: PID DIRECCION (ej. 3 0x1A2F)".to_string()],
+        },
+        _ => return vec!["Formato: PID DIRECCION (ej. 3 0x1A2F)".to_string()],
+    };
+
+    match gestor.traducir_direccion(pid, direccion) {
+        Ok(traduccion) => traduccion.pasos,
+        Err(error) => vec![format!("[ERROR] {}", error)],
+    }
+}
+
 /// Helper para crear un proceso con parámetros aleatorios
 fn crear_proceso(gestor: &mut GestorMemoria, contador_pid: &mut usize) {
     let mut rng = rand::thread_rng();
@@ -232,12 +341,21 @@ fn crear_proceso(gestor: &mut GestorMemoria, contador_pid: &mut usize) {
         rng.gen_range(50..255),
     );
 
-    gestor.asignar_proceso(Proceso {
-        pid: *contador_pid,
-        nombre: format!("P_{}", contador_pid),
-        tamaño_kb: tamaño,
-        paginas: vec![],
-        color: (r, g, b),
-    });
-    *contador_pid += 1;
+    let mut proceso = Proceso::new(
+        *contador_pid,
+        &format!("P_{}", contador_pid),
+        tamaño,
+        (r, g, b),
+    );
+    proceso.prioridad = rng.gen_range(1..=5);
+    match gestor.asignar_proceso(proceso) {
+        Ok(()) => *contador_pid += 1,
+        Err(error @ ErrorMemoria::LimiteProcesos { .. }) => {
+            gestor.registrar_log(&format!("[ERROR] {}", error));
+        }
+        Err(rechazo) => gestor.registrar_log(&format!(
+            "[RECHAZO] Proceso P{} no admitido: {}",
+            contador_pid, rechazo
+        )),
+    }
 }
@@ -1,32 +1,396 @@
+use crate::buddy::{AsignadorBuddy, BLOQUE_MINIMO_BUDDY};
+use crate::contigua::{EstrategiaAjuste, MemoriaContigua};
+use crate::error::ErrorMemoria;
+use crate::politicas::{
+    fallos_optimos, Aleatoria, ClockPro, DosColas, Envejecimiento, FabricaPolitica, Fifo, Lfu, Lru,
+    Mfu, Nru, Optimo, PoliticaArc, PoliticaReemplazo, Reloj, RelojMejorado, VistaMemoria, WsClock,
+};
+use crate::slab::{caches_por_defecto, AsignadorSlab, EstadoSlab};
+use crate::tablas::{
+    CosteBusqueda, OrganizacionTabla, ParametrosTablaPaginas, TablaHash, TablaInvertida,
+};
+use crate::tlb::{LatenciasAcceso, ParametrosTlb, Tlb};
 use rand::seq::SliceRandom;
-use std::collections::VecDeque;
+use rand::Rng;
+use std::cell::Cell;
+use std::collections::{HashSet, VecDeque};
+
+/// Procesos activos permitidos por defecto (`GestorMemoria::max_procesos`)
+pub const MAX_PROCESOS: usize = 30;
+
+/// Referencias recientes sobre las que se compara el reemplazo con Belady MIN
+pub const VENTANA_OPTIMO: usize = 4096;
 
 // --- ENUMERACIONES Y ESTRUCTURAS DE DATOS ---
 
-#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum AlgoritmoReemplazo {
-    FIFO,  // First In First Out
-    LRU,   // Least Recently Used
-    Reloj, // Algoritmo del Reloj (segunda oportunidad)
+    FIFO,           // First In First Out
+    LRU,            // Least Recently Used
+    Reloj,          // Algoritmo del Reloj (segunda oportunidad)
+    Optimo,         // Óptimo de Belady (requiere conocer las referencias futuras)
+    LFU,            // Least Frequently Used
+    MFU,            // Most Frequently Used
+    NRU,            // Not Recently Used (clases R/M)
+    RelojMejorado,  // Segunda oportunidad mejorada (prefiere páginas limpias)
+    Envejecimiento, // Aging: contador de N bits desplazado en cada tick
+    WSClock,        // Reloj de working set (Carr y Hennessy)
+    ARC,            // Adaptive Replacement Cache (T1/T2 + fantasmas B1/B2)
+    DosQ,           // 2Q (A1in / A1out / Am)
+    Aleatoria,      // Víctima aleatoria con semilla (línea base)
+    ClockPro,       // CLOCK-Pro (calientes / frías / prueba, tres manecillas)
+}
+
+impl AlgoritmoReemplazo {
+    /// Algoritmos incluidos, en el orden de las teclas numéricas del simulador
+    pub const TODOS: [AlgoritmoReemplazo; 14] = [
+        AlgoritmoReemplazo::FIFO,
+        AlgoritmoReemplazo::Reloj,
+        AlgoritmoReemplazo::LRU,
+        AlgoritmoReemplazo::Optimo,
+        AlgoritmoReemplazo::LFU,
+        AlgoritmoReemplazo::MFU,
+        AlgoritmoReemplazo::NRU,
+        AlgoritmoReemplazo::RelojMejorado,
+        AlgoritmoReemplazo::Envejecimiento,
+        AlgoritmoReemplazo::WSClock,
+        AlgoritmoReemplazo::ARC,
+        AlgoritmoReemplazo::DosQ,
+        AlgoritmoReemplazo::Aleatoria,
+        AlgoritmoReemplazo::ClockPro,
+    ];
+
+    /// Nombre con el que el algoritmo queda registrado en el gestor
+    pub fn nombre(self) -> &'static str {
+        match self {
+            AlgoritmoReemplazo::FIFO => "FIFO",
+            AlgoritmoReemplazo::LRU => "LRU",
+            AlgoritmoReemplazo::Reloj => "Reloj",
+            AlgoritmoReemplazo::Optimo => "Optimo",
+            AlgoritmoReemplazo::LFU => "LFU",
+            AlgoritmoReemplazo::MFU => "MFU",
+            AlgoritmoReemplazo::NRU => "NRU",
+            AlgoritmoReemplazo::RelojMejorado => "RelojMejorado",
+            AlgoritmoReemplazo::Envejecimiento => "Envejecimiento",
+            AlgoritmoReemplazo::WSClock => "WSClock",
+            AlgoritmoReemplazo::ARC => "ARC",
+            AlgoritmoReemplazo::DosQ => "2Q",
+            AlgoritmoReemplazo::Aleatoria => "Aleatoria",
+            AlgoritmoReemplazo::ClockPro => "CLOCK-Pro",
+        }
+    }
+
+    /// Descripción para el menú de selección
+    pub fn descripcion(self) -> &'static str {
+        match self {
+            AlgoritmoReemplazo::FIFO => "FIFO (Primero en Entrar, Primero en Salir)",
+            AlgoritmoReemplazo::LRU => "LRU (Menos Recientemente Usado)",
+            AlgoritmoReemplazo::Reloj => "Reloj (Segunda Oportunidad)",
+            AlgoritmoReemplazo::Optimo => "Óptimo (Belady MIN, usa referencias futuras)",
+            AlgoritmoReemplazo::LFU => "LFU (Menos Frecuentemente Usado)",
+            AlgoritmoReemplazo::MFU => "MFU (Más Frecuentemente Usado)",
+            AlgoritmoReemplazo::NRU => "NRU (No Usado Recientemente, clases R/M)",
+            AlgoritmoReemplazo::RelojMejorado => "Reloj Mejorado (Segunda Oportunidad con bit M)",
+            AlgoritmoReemplazo::Envejecimiento => "Envejecimiento / Aging (contador de N bits)",
+            AlgoritmoReemplazo::WSClock => "WSClock (Reloj sobre el working set)",
+            AlgoritmoReemplazo::ARC => "ARC (Adaptive Replacement Cache)",
+            AlgoritmoReemplazo::DosQ => "2Q (A1in / A1out / Am)",
+            AlgoritmoReemplazo::Aleatoria => "Aleatoria (con semilla, línea base)",
+            AlgoritmoReemplazo::ClockPro => "CLOCK-Pro (calientes / frías / prueba)",
+        }
+    }
+
+    /// Busca un algoritmo incluido por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|alg| alg.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+
+    /// Construye la política de reemplazo correspondiente
+    pub fn crear_politica(self, parametros: &ParametrosPoliticas) -> Box<dyn PoliticaReemplazo> {
+        match self {
+            AlgoritmoReemplazo::FIFO => Box::new(Fifo::default()),
+            AlgoritmoReemplazo::LRU => Box::new(Lru),
+            AlgoritmoReemplazo::Reloj => Box::new(Reloj::default()),
+            AlgoritmoReemplazo::Optimo => Box::new(Optimo),
+            AlgoritmoReemplazo::LFU => Box::new(Lfu),
+            AlgoritmoReemplazo::MFU => Box::new(Mfu),
+            AlgoritmoReemplazo::NRU => Box::new(Nru),
+            AlgoritmoReemplazo::RelojMejorado => Box::new(RelojMejorado::default()),
+            AlgoritmoReemplazo::Envejecimiento => {
+                Box::new(Envejecimiento::new(parametros.bits_envejecimiento))
+            }
+            AlgoritmoReemplazo::WSClock => Box::new(WsClock::new(parametros.ventana_ws)),
+            AlgoritmoReemplazo::ARC => Box::new(PoliticaArc::default()),
+            AlgoritmoReemplazo::DosQ => Box::new(DosColas::default()),
+            AlgoritmoReemplazo::Aleatoria => Box::new(Aleatoria::new(parametros.semilla_aleatoria)),
+            AlgoritmoReemplazo::ClockPro => Box::new(ClockPro::default()),
+        }
+    }
+}
+
+/// Parámetros configurables de las políticas de reemplazo (config.ini)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParametrosPoliticas {
+    pub intervalo_decaimiento: usize, // Accesos entre decaimientos LFU/MFU (0 = nunca)
+    pub bits_envejecimiento: u32,     // Ancho del registro de Aging (1-64)
+    pub ventana_ws: u64,              // Ventana Δ del working set en tiempo virtual
+    pub semilla_aleatoria: u64,       // Semilla de la política Aleatoria
+}
+
+impl Default for ParametrosPoliticas {
+    fn default() -> Self {
+        Self {
+            intervalo_decaimiento: 0,
+            bits_envejecimiento: 8,
+            ventana_ws: 16,
+            semilla_aleatoria: 42,
+        }
+    }
+}
+
+/// Parámetros del asignador PFF (Page-Fault-Frequency): cada `ventana`
+/// referencias de un proceso se mide su tasa de fallos; por encima del umbral
+/// superior gana un marco de cuota y por debajo del inferior lo pierde
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParametrosPff {
+    pub umbral_superior: f64, // Tasa de fallos (0-1) a partir de la cual crece la cuota
+    pub umbral_inferior: f64, // Tasa de fallos (0-1) por debajo de la cual decrece
+    pub ventana: usize,       // Referencias del proceso entre dos mediciones
+}
+
+impl Default for ParametrosPff {
+    fn default() -> Self {
+        Self {
+            umbral_superior: 0.30,
+            umbral_inferior: 0.05,
+            ventana: 20,
+        }
+    }
+}
+
+/// Alcance del reemplazo: de qué procesos puede salir la página víctima
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlcanceReemplazo {
+    Global, // Cualquier marco de la RAM (un proceso puede quitarle marcos a otro)
+    Local,  // Solo marcos del proceso que provoca el fallo
+    Cuota,  // Cada proceso tiene una cuota de marcos; al alcanzarla reemplaza localmente
+}
+
+impl AlcanceReemplazo {
+    pub const TODOS: [AlcanceReemplazo; 3] = [
+        AlcanceReemplazo::Global,
+        AlcanceReemplazo::Local,
+        AlcanceReemplazo::Cuota,
+    ];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            AlcanceReemplazo::Global => "Global",
+            AlcanceReemplazo::Local => "Local",
+            AlcanceReemplazo::Cuota => "Cuota",
+        }
+    }
+
+    /// Busca un alcance por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|alc| alc.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+}
+
+/// Reparto inicial de marcos entre procesos cuando el alcance es por cuota
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AsignacionInicial {
+    Equitativa,   // Todos los procesos reciben la misma cuota
+    Proporcional, // Cuota proporcional al número de páginas del proceso
+    Prioridad,    // Cuota proporcional a la prioridad del proceso
+}
+
+impl AsignacionInicial {
+    pub const TODOS: [AsignacionInicial; 3] = [
+        AsignacionInicial::Equitativa,
+        AsignacionInicial::Proporcional,
+        AsignacionInicial::Prioridad,
+    ];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            AsignacionInicial::Equitativa => "Equitativa",
+            AsignacionInicial::Proporcional => "Proporcional",
+            AsignacionInicial::Prioridad => "Prioridad",
+        }
+    }
+
+    /// Busca un reparto por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|asig| asig.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+
+    /// Peso del proceso en el reparto de marcos
+    pub fn peso(self, proceso: &Proceso) -> usize {
+        match self {
+            AsignacionInicial::Equitativa => 1,
+            AsignacionInicial::Proporcional => proceso.paginas.len(),
+            AsignacionInicial::Prioridad => proceso.prioridad as usize,
+        }
+    }
+}
+
+/// Cómo se reparte la RAM entre los procesos
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModoMemoria {
+    Paginacion, // Marcos de tamaño fijo, tablas de páginas y Swap
+    Contigua,   // Particiones variables: un bloque contiguo por proceso
+    Buddy,      // Sistema buddy: bloques de potencia de dos que se parten y fusionan
+}
+
+impl ModoMemoria {
+    pub const TODOS: [ModoMemoria; 3] = [
+        ModoMemoria::Paginacion,
+        ModoMemoria::Contigua,
+        ModoMemoria::Buddy,
+    ];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            ModoMemoria::Paginacion => "Paginacion",
+            ModoMemoria::Contigua => "Contigua",
+            ModoMemoria::Buddy => "Buddy",
+        }
+    }
+
+    /// Busca un modo por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|modo| modo.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+}
+
+/// Tipo de acceso a memoria: las escrituras marcan la página como modificada
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TipoAcceso {
+    Lectura,
+    Escritura,
 }
 
 /// Representa una página lógica de un proceso
 #[derive(Clone, Debug, PartialEq)]
 pub struct Pagina {
-    pub id: usize,               // ID de la página lógica
-    pub marco_id: Option<usize>, // Marco físico asignado (None = en Swap)
-    pub referenciada: bool,      // Bit de referencia (para algoritmo Reloj)
-    pub ultimo_uso: u64,         // Timestamp del último acceso (para LRU)
+    pub id: usize,                       // ID de la página lógica
+    pub marco_id: Option<usize>,         // Marco físico asignado (None = en Swap)
+    pub referenciada: bool,              // Bit de referencia (para algoritmo Reloj)
+    pub ultimo_uso: u64,                 // Timestamp del último acceso (para LRU)
+    pub contador_accesos: u64,           // Referencias desde que se cargó (para LFU/MFU)
+    pub modificada: bool,                // Bit M: escrita desde que se cargó (volcar a Swap)
+    pub edad: u64,                       // Registro de envejecimiento (para Aging)
+    pub ultimo_uso_virtual: Option<u64>, // Tiempo virtual del último uso (None = nunca usada)
 }
 
 /// Representa un proceso en el sistema
 #[derive(Clone, Debug)]
 pub struct Proceso {
-    pub pid: usize,           // Process ID único
-    pub nombre: String,       // Nombre del proceso
-    pub tamaño_kb: usize,     // Tamaño total en KB
-    pub paginas: Vec<Pagina>, // Tabla de páginas del proceso
-    pub color: (u8, u8, u8),  // Color RGB para visualización
+    pub pid: usize,                        // Process ID único
+    pub nombre: String,                    // Nombre del proceso
+    pub tamaño_kb: usize,                  // Tamaño total en KB
+    pub paginas: Vec<Pagina>,              // Tabla de páginas del proceso
+    pub color: (u8, u8, u8),               // Color RGB para visualización
+    pub referencias: GeneradorReferencias, // Flujo de accesos a memoria del proceso
+    pub tiempo_virtual: u64,               // Referencias realizadas por el proceso
+    pub prioridad: u8,                     // Prioridad (1 = mínima) para el reparto por prioridad
+    pub fallos_pagina: usize,              // Fallos de página provocados por el proceso
+    pub cuota_marcos: Option<usize>,       // Marcos asignados (None = sin límite)
+    pub fallos_ventana: usize,             // Fallos en la ventana de medición PFF actual
+    pub referencias_ventana: usize,        // Referencias en la ventana de medición PFF actual
+}
+
+/// Generador de referencias a memoria con localidad: el proceso trabaja sobre
+/// una ventana de páginas consecutivas y de vez en cuando salta a otra zona.
+#[derive(Clone, Debug)]
+pub struct GeneradorReferencias {
+    pub inicio: usize,       // Primera página de la localidad actual
+    pub ancho: usize,        // Número de páginas en la localidad
+    pub prob_cambio: f64,    // Probabilidad de cambiar de localidad en cada referencia
+    pub prob_escritura: f64, // Probabilidad de que una referencia sea escritura
+}
+
+impl Default for GeneradorReferencias {
+    fn default() -> Self {
+        Self {
+            inicio: 0,
+            ancho: 2,
+            prob_cambio: 0.1,
+            prob_escritura: 0.3,
+        }
+    }
+}
+
+impl GeneradorReferencias {
+    /// Devuelve la siguiente página referenciada de un proceso con `total_paginas`
+    /// y si el acceso es lectura o escritura
+    pub fn siguiente<R: Rng>(
+        &mut self,
+        total_paginas: usize,
+        rng: &mut R,
+    ) -> Option<(usize, TipoAcceso)> {
+        if total_paginas == 0 {
+            return None;
+        }
+        if rng.gen_bool(self.prob_cambio) {
+            self.inicio = rng.gen_range(0..total_paginas);
+        }
+        let ancho = self.ancho.clamp(1, total_paginas);
+        let pagina = (self.inicio + rng.gen_range(0..ancho)) % total_paginas;
+        let tipo = if rng.gen_bool(self.prob_escritura) {
+            TipoAcceso::Escritura
+        } else {
+            TipoAcceso::Lectura
+        };
+        Some((pagina, tipo))
+    }
+}
+
+impl Proceso {
+    /// Crea un proceso con tabla de páginas vacía (se rellena al asignarlo)
+    pub fn new(pid: usize, nombre: &str, tamaño_kb: usize, color: (u8, u8, u8)) -> Self {
+        Self {
+            pid,
+            nombre: nombre.to_string(),
+            tamaño_kb,
+            paginas: Vec::new(),
+            color,
+            referencias: GeneradorReferencias::default(),
+            tiempo_virtual: 0,
+            prioridad: 1,
+            fallos_pagina: 0,
+            cuota_marcos: None,
+            fallos_ventana: 0,
+            referencias_ventana: 0,
+        }
+    }
+
+    /// Número de páginas del proceso actualmente cargadas en RAM
+    pub fn paginas_en_ram(&self) -> usize {
+        self.paginas.iter().filter(|p| p.marco_id.is_some()).count()
+    }
+
+    /// Tamaño del working set W(t, Δ): páginas referenciadas en las últimas
+    /// `ventana` unidades de tiempo virtual del proceso
+    pub fn working_set(&self, ventana: u64) -> usize {
+        self.paginas
+            .iter()
+            .filter(|p| {
+                p.ultimo_uso_virtual
+                    .is_some_and(|uso| self.tiempo_virtual.saturating_sub(uso) < ventana)
+            })
+            .count()
+    }
 }
 
 /// Representa un marco de página física en RAM
@@ -35,40 +399,123 @@ pub struct Marco {
     pub id: usize,                 // ID del marco físico
     pub proceso_id: Option<usize>, // PID del proceso que lo ocupa (None = libre)
     pub pagina_id: Option<usize>,  // ID de página lógica asignada
+    pub nucleo: bool,              // Reservado por el núcleo para un slab (fuera de la paginación)
+}
+
+impl Marco {
+    /// Ni un proceso ni el núcleo lo ocupan
+    pub fn libre(&self) -> bool {
+        self.proceso_id.is_none() && !self.nucleo
+    }
+}
+
+/// Resultado de traducir una dirección virtual a física (en los modos
+/// contiguo y buddy la traducción es base + límite y `pagina` y `marco` valen 0)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Traduccion {
+    pub pid: usize,
+    pub direccion_virtual: usize,
+    pub pagina: usize,         // Número de página virtual
+    pub desplazamiento: usize, // Offset dentro de la página (bytes)
+    pub acierto_tlb: bool,     // La TLB resolvió la traducción sin ir a la tabla
+    pub fallo: bool,           // La página no estaba en RAM y hubo que traerla
+    pub marco: usize,          // Marco físico que contiene la página
+    pub direccion_fisica: usize,
+    pub pasos: Vec<String>, // Explicación de cada paso, para mostrarla en la interfaz
+}
+
+/// Instantánea de las métricas de rendimiento del gestor
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Metricas {
+    pub fallos_pagina: usize,
+    pub accesos_totales: usize,
+    pub swaps_salida: usize,
+    pub swaps_entrada: usize,
+    pub escrituras_swap: usize,
+    pub robos_marcos: usize, // Reemplazos cuya víctima era de otro proceso
+    pub robos_nucleo: usize, // Reemplazos cuyo marco pasó a un slab del núcleo
+    pub aciertos_tlb: usize,
+    pub fallos_tlb: usize,
+    pub referencias_tabla: usize, // Accesos a memoria para recorrer tablas de páginas
+    pub memoria_tablas: usize,    // Bytes ocupados por las tablas de páginas
+    pub fragmentacion_interna: usize, // KB asignados que los procesos no usan
+    pub procesos_creados: usize,
+    pub procesos_finalizados: usize,
+    pub fallos_ventana: usize, // Fallos en las últimas VENTANA_OPTIMO referencias
+    pub fallos_optimos: usize, // Fallos de Belady MIN sobre esa misma ventana
+    pub tasa_fallos: f64,      // Porcentaje de fallos sobre accesos
+    pub utilizacion_ram: f64,  // Porcentaje de marcos ocupados
+    pub tasa_aciertos_tlb: f64, // Porcentaje de consultas resueltas por la TLB
+    pub tiempo_acceso_efectivo: f64, // EAT en nanosegundos
 }
 
 /// Gestor principal de memoria RAM y Swap
 pub struct GestorMemoria {
-    pub marcos_ram: Vec<Marco>,              // Memoria física (RAM)
-    pub cola_swap: VecDeque<(usize, usize)>, // Cola de Swap: (PID, página)
-    pub procesos: Vec<Proceso>,              // Procesos activos en el sistema
-    pub tamaño_pagina_kb: usize,             // Tamaño de página/marco en KB
-    pub logs: Vec<String>,                   // Registro de eventos
-    pub algoritmo: AlgoritmoReemplazo,       // Algoritmo de reemplazo activo
-    pub puntero_reloj: usize,                // Puntero para algoritmo Reloj y FIFO
+    pub marcos_ram: Vec<Marco>,                         // Memoria física (RAM)
+    pub cola_swap: VecDeque<(usize, usize)>,            // Cola de Swap: (PID, página)
+    pub procesos: Vec<Proceso>,                         // Procesos activos en el sistema
+    pub max_procesos: usize,                            // Límite de procesos activos
+    pub tamaño_pagina_kb: usize,                        // Tamaño de página/marco en KB
+    pub capacidad_swap: usize, // Páginas que caben en Swap (SWAP_SIZE / PAGE_SIZE)
+    pub logs: Vec<String>,     // Registro de eventos
+    pub politica: Box<dyn PoliticaReemplazo>, // Política de reemplazo activa
+    registro_politicas: Vec<(String, FabricaPolitica)>, // Políticas externas registradas
+    pub parametros: ParametrosPoliticas, // Parámetros de las políticas incluidas
+    pub alcance: AlcanceReemplazo, // De qué procesos puede salir la víctima
+    pub asignacion: AsignacionInicial, // Reparto de cuotas con alcance por cuota
+    pub pff: Option<ParametrosPff>, // Ajuste dinámico de cuotas por PFF (None = cuotas fijas)
+    pub tlb: Tlb,              // Caché de traducciones delante de las tablas de páginas
+    pub latencias: LatenciasAcceso, // Latencias para el tiempo efectivo de acceso
+    pub tabla_paginas: ParametrosTablaPaginas, // Niveles de la tabla de páginas
+    pub organizacion: OrganizacionTabla, // Tabla que se recorre tras un fallo de TLB
+    pub tabla_invertida: TablaInvertida, // Una entrada por marco + anclas hash
+    pub tabla_hash: TablaHash, // Nodos por página virtual con cadenas de colisión
+    pub costes_busqueda: [CosteBusqueda; 3], // Coste de cada organización (por `indice`)
+    pub modo: ModoMemoria,     // Paginación, particiones variables o buddy
+    pub contigua: MemoriaContigua, // Bloques y huecos del modo contiguo
+    pub buddy: AsignadorBuddy, // Árbol y listas libres del modo buddy
+    pub slab: AsignadorSlab,   // Cachés de objetos del núcleo sobre `marcos_ram`
+
+    // --- CADENA DE REFERENCIAS ---
+    pub referencias_pendientes: VecDeque<(usize, usize, TipoAcceso)>, // Referencias futuras conocidas
+    pub historial_referencias: VecDeque<(usize, usize, bool)>, // Últimas VENTANA_OPTIMO referencias (¿fallo?)
+    referencias_registradas: usize, // Referencias que han pasado por el historial
+    cache_optimo: Cell<(usize, usize)>, // (referencias registradas, fallos óptimos)
 
     // --- MÉTRICAS DE RENDIMIENTO ---
     pub fallos_pagina: usize,        // Total de page faults
     pub accesos_totales: usize,      // Total de accesos a memoria
-    pub swaps_realizados: usize,     // Número de páginas enviadas a Swap
+    pub swaps_salida: usize,         // Número de páginas enviadas a Swap
+    pub swaps_entrada: usize,        // Número de páginas traídas de vuelta desde Swap
+    pub escrituras_swap: usize,      // Páginas modificadas volcadas a Swap (write-backs)
+    pub robos_marcos: usize,         // Reemplazos que quitaron el marco a otro proceso
+    pub robos_nucleo: usize,         // Reemplazos que quitaron el marco a un proceso para un slab
+    pub referencias_tabla: usize,    // Accesos a tablas de páginas tras un fallo de TLB
     pub procesos_creados: usize,     // Total de procesos creados
     pub procesos_finalizados: usize, // Total de procesos terminados
 }
 
 impl GestorMemoria {
-    /// Crea un nuevo gestor de memoria con la configuración especificada
+    /// Crea un nuevo gestor de memoria con la configuración especificada.
+    /// `tamaño_pagina` debe ser mayor que cero (`Configuracion::cargar` ya lo
+    /// comprueba); con cero el constructor entra en pánico.
     pub fn new(
         tamaño_ram: usize,
         tamaño_swap: usize,
         tamaño_pagina: usize,
         algoritmo: AlgoritmoReemplazo,
     ) -> Self {
+        assert!(
+            tamaño_pagina > 0,
+            "El tamaño de página debe ser mayor que 0"
+        );
         let total_marcos = tamaño_ram / tamaño_pagina;
         let marcos = (0..total_marcos)
             .map(|i| Marco {
                 id: i,
                 proceso_id: None,
                 pagina_id: None,
+                nucleo: false,
             })
             .collect();
 
@@ -76,17 +523,52 @@ impl GestorMemoria {
             marcos_ram: marcos,
             cola_swap: VecDeque::new(),
             procesos: Vec::new(),
+            max_procesos: MAX_PROCESOS,
             tamaño_pagina_kb: tamaño_pagina,
+            capacidad_swap: tamaño_swap / tamaño_pagina,
             logs: vec![format!(
-                "[INICIO] Sistema iniciado - Algoritmo: {:?} | RAM: {}KB | Swap: {}KB",
-                algoritmo, tamaño_ram, tamaño_swap
+                "[INICIO] Sistema iniciado - Algoritmo: {:?} | RAM: {}KB | Swap: {}KB ({} páginas)",
+                algoritmo,
+                tamaño_ram,
+                tamaño_swap,
+                tamaño_swap / tamaño_pagina
             )],
-            algoritmo,
-            puntero_reloj: 0,
+            politica: algoritmo.crear_politica(&ParametrosPoliticas::default()),
+            registro_politicas: Vec::new(),
+            referencias_pendientes: VecDeque::new(),
+            historial_referencias: VecDeque::new(),
+            referencias_registradas: 0,
+            cache_optimo: Cell::new((0, 0)),
+            parametros: ParametrosPoliticas::default(),
+            alcance: AlcanceReemplazo::Global,
+            asignacion: AsignacionInicial::Equitativa,
+            pff: None,
+            tlb: Tlb::new(ParametrosTlb::default()),
+            latencias: LatenciasAcceso::default(),
+            tabla_paginas: ParametrosTablaPaginas::default(),
+            organizacion: OrganizacionTabla::Jerarquica,
+            tabla_invertida: TablaInvertida::new(total_marcos),
+            tabla_hash: TablaHash::new(total_marcos),
+            costes_busqueda: [CosteBusqueda::default(); 3],
+            modo: ModoMemoria::Paginacion,
+            contigua: MemoriaContigua::new(
+                total_marcos * tamaño_pagina,
+                EstrategiaAjuste::PrimerAjuste,
+            ),
+            buddy: AsignadorBuddy::new(total_marcos * tamaño_pagina, BLOQUE_MINIMO_BUDDY),
+            slab: AsignadorSlab::new(
+                tamaño_pagina * 1024,
+                &caches_por_defecto(tamaño_pagina * 1024),
+            ),
             // Inicializar métricas
             fallos_pagina: 0,
             accesos_totales: 0,
-            swaps_realizados: 0,
+            swaps_salida: 0,
+            swaps_entrada: 0,
+            escrituras_swap: 0,
+            robos_marcos: 0,
+            robos_nucleo: 0,
+            referencias_tabla: 0,
             procesos_creados: 0,
             procesos_finalizados: 0,
         }
@@ -102,14 +584,28 @@ impl GestorMemoria {
 
     /// Cuenta cuántos marcos están libres en RAM
     pub fn contar_marcos_libres(&self) -> usize {
-        self.marcos_ram
+        self.marcos_ram.iter().filter(|marco| marco.libre()).count()
+    }
+
+    /// Marcos que se pueden ocupar sin desalojar ninguna página: los libres
+    /// y los de slabs vacíos, que el núcleo devuelve antes de reemplazar
+    fn marcos_disponibles(&self) -> usize {
+        let vacios: usize = self
+            .slab
+            .caches
             .iter()
-            .filter(|marco| marco.proceso_id.is_none())
-            .count()
+            .map(|c| c.contar(EstadoSlab::Vacio))
+            .sum();
+        self.contar_marcos_libres() + vacios
     }
 
     /// Calcula el porcentaje de utilización de RAM
     pub fn utilización_ram(&self) -> f64 {
+        match self.modo {
+            ModoMemoria::Paginacion => {}
+            ModoMemoria::Contigua => return self.contigua.utilizacion(),
+            ModoMemoria::Buddy => return self.buddy.utilizacion(),
+        }
         let total = self.marcos_ram.len();
         if total == 0 {
             return 0.0;
@@ -126,216 +622,1741 @@ impl GestorMemoria {
         (self.fallos_pagina as f64 / self.accesos_totales as f64) * 100.0
     }
 
-    /// Avanza el puntero del reloj (para FIFO y algoritmo Reloj)
-    fn avanzar_reloj(&mut self) {
-        let len = self.marcos_ram.len();
-        if len > 0 {
-            self.puntero_reloj = (self.puntero_reloj + 1) % len;
-        }
+    /// Tiempo efectivo de acceso (EAT) en ns con las tasas observadas:
+    /// `(1 - p) * [h * (t + m) + (1 - h) * (t + (n + 1) * m)] + p * fallo`,
+    /// con `h` la tasa de aciertos de la TLB, `p` la de fallos de página y
+    /// `n` los accesos a memoria de cada búsqueda en la tabla de páginas
+    pub fn tiempo_acceso_efectivo(&self) -> f64 {
+        let LatenciasAcceso {
+            memoria_ns: m,
+            tlb_ns,
+            fallo_ns,
+        } = self.latencias;
+        let t = if self.tlb.parametros.entradas == 0 {
+            0.0
+        } else {
+            tlb_ns
+        };
+        let h = self.tlb.tasa_aciertos() / 100.0;
+        let p = self.tasa_fallos() / 100.0;
+        let n = self.accesos_por_busqueda();
+        let acceso = h * (t + m) + (1.0 - h) * (t + (n + 1.0) * m);
+        (1.0 - p) * acceso + p * fallo_ns
     }
 
-    /// Obtiene referencia mutable a una página específica de un proceso
-    fn obtener_info_pagina(&mut self, pid: usize, idx_pagina: usize) -> Option<&mut Pagina> {
-        if let Some(proceso) = self.procesos.iter_mut().find(|p| p.pid == pid) {
-            proceso.paginas.iter_mut().find(|p| p.id == idx_pagina)
-        } else {
-            None
+    /// Fallos que habría tenido el algoritmo óptimo sobre las últimas
+    /// `VENTANA_OPTIMO` referencias con los mismos marcos, sin contar la
+    /// primera referencia a cada página (cota inferior de `fallos_ventana`)
+    pub fn fallos_optimos(&self) -> usize {
+        let (registradas, fallos) = self.cache_optimo.get();
+        if registradas == self.referencias_registradas {
+            return fallos;
         }
+        let cadena: Vec<(usize, usize)> = self
+            .historial_referencias
+            .iter()
+            .map(|&(pid, pagina, _)| (pid, pagina))
+            .collect();
+        let distintas = cadena.iter().collect::<HashSet<_>>().len();
+        let fallos = fallos_optimos(&cadena, self.marcos_ram.len()) - distintas;
+        self.cache_optimo
+            .set((self.referencias_registradas, fallos));
+        fallos
     }
 
-    /// Asigna un nuevo proceso al sistema (Requisito B y C)
-    pub fn asignar_proceso(&mut self, mut proceso: Proceso) {
-        let paginas_necesarias =
-            (proceso.tamaño_kb as f64 / self.tamaño_pagina_kb as f64).ceil() as usize;
+    /// Fallos de página reales en la misma ventana que `fallos_optimos`
+    /// (tampoco cuenta la primera referencia a cada página; la precarga no
+    /// forma parte del historial)
+    pub fn fallos_ventana(&self) -> usize {
+        let mut vistas = HashSet::new();
+        self.historial_referencias
+            .iter()
+            .filter(|&&(pid, pagina, fallo)| !vistas.insert((pid, pagina)) && fallo)
+            .count()
+    }
 
-        // Crear tabla de páginas del proceso
-        for i in 0..paginas_necesarias {
-            proceso.paginas.push(Pagina {
-                id: i,
-                marco_id: None,
-                referenciada: true,
-                ultimo_uso: 0,
-            });
+    /// Devuelve una instantánea de todas las métricas de rendimiento
+    pub fn metricas(&self) -> Metricas {
+        Metricas {
+            fallos_pagina: self.fallos_pagina,
+            accesos_totales: self.accesos_totales,
+            swaps_salida: self.swaps_salida,
+            swaps_entrada: self.swaps_entrada,
+            escrituras_swap: self.escrituras_swap,
+            robos_marcos: self.robos_marcos,
+            robos_nucleo: self.robos_nucleo,
+            aciertos_tlb: self.tlb.aciertos,
+            fallos_tlb: self.tlb.fallos,
+            referencias_tabla: self.referencias_tabla,
+            memoria_tablas: self.memoria_tablas_total(),
+            fragmentacion_interna: self.fragmentacion_interna_total(),
+            procesos_creados: self.procesos_creados,
+            procesos_finalizados: self.procesos_finalizados,
+            fallos_ventana: self.fallos_ventana(),
+            fallos_optimos: self.fallos_optimos(),
+            tasa_fallos: self.tasa_fallos(),
+            utilizacion_ram: self.utilización_ram(),
+            tasa_aciertos_tlb: self.tlb.tasa_aciertos(),
+            tiempo_acceso_efectivo: self.tiempo_acceso_efectivo(),
         }
+    }
 
-        self.procesos_creados += 1;
-        self.registrar_log(&format!(
-            "[NUEVO] Proceso P{} '{}' creado ({}KB, {} páginas)",
-            proceso.pid, proceso.nombre, proceso.tamaño_kb, paginas_necesarias
-        ));
+    /// Bytes que ocupan las tablas de páginas del proceso (todas sus tablas
+    /// de cada nivel, solo las que cubren páginas que existen)
+    pub fn memoria_tablas(&self, pid: usize) -> Option<usize> {
+        let proceso = self.proceso(pid)?;
+        Some(
+            self.tabla_paginas
+                .memoria_tablas(proceso.paginas.iter().map(|p| p.id)),
+        )
+    }
 
-        // Intentar cargar todas las páginas del proceso
-        for idx_pagina in 0..paginas_necesarias {
-            if let Err(error) = self.cargar_pagina(proceso.pid, idx_pagina) {
-                self.registrar_log(&format!("[ERROR] {}", error));
-                return;
-            }
+    /// Bytes que ocupan las tablas de páginas con la organización activa
+    /// (las invertida y hash son una sola tabla para todo el sistema)
+    pub fn memoria_tablas_total(&self) -> usize {
+        let tamaño_entrada = self.tabla_paginas.tamaño_entrada;
+        match self.organizacion {
+            OrganizacionTabla::Jerarquica => self
+                .procesos
+                .iter()
+                .filter_map(|p| self.memoria_tablas(p.pid))
+                .sum(),
+            OrganizacionTabla::Invertida => self.tabla_invertida.memoria(tamaño_entrada),
+            OrganizacionTabla::Hash => self.tabla_hash.memoria(tamaño_entrada),
         }
+    }
 
-        self.procesos.push(proceso);
+    /// KB reservados para el proceso que no forman parte de su tamaño: el
+    /// resto de la última página en paginación o el redondeo a potencia de
+    /// dos en buddy (las particiones variables se ajustan al tamaño exacto)
+    pub fn fragmentacion_interna(&self, pid: usize) -> Option<usize> {
+        let proceso = self.proceso(pid)?;
+        Some(match self.modo {
+            ModoMemoria::Paginacion => {
+                (proceso.paginas.len() * self.tamaño_pagina_kb).saturating_sub(proceso.tamaño_kb)
+            }
+            ModoMemoria::Contigua => 0,
+            ModoMemoria::Buddy => self
+                .buddy
+                .bloque_de(pid)
+                .map_or(0, |(_, bloque)| bloque.fragmentacion_interna()),
+        })
     }
 
-    /// Mata un proceso aleatorio y libera sus recursos (Requisito B)
-    pub fn matar_proceso_aleatorio(&mut self) {
-        if self.procesos.is_empty() {
-            return;
-        }
+    /// Fragmentación interna sumando todos los procesos activos
+    pub fn fragmentacion_interna_total(&self) -> usize {
+        self.procesos
+            .iter()
+            .filter_map(|p| self.fragmentacion_interna(p.pid))
+            .sum()
+    }
 
-        let mut rng = rand::thread_rng();
-        let pid_victima = self.procesos.choose(&mut rng).unwrap().pid;
+    /// Coste acumulado de las búsquedas en la organización indicada
+    pub fn coste_busqueda(&self, organizacion: OrganizacionTabla) -> CosteBusqueda {
+        self.costes_busqueda[organizacion.indice()]
+    }
 
-        // Liberar marcos RAM ocupados por el proceso
-        for marco in &mut self.marcos_ram {
-            if marco.proceso_id == Some(pid_victima) {
-                marco.proceso_id = None;
-                marco.pagina_id = None;
-            }
+    /// Accesos a memoria de una búsqueda en la tabla activa: los niveles de
+    /// la jerárquica o la media observada en las invertida y hash
+    pub fn accesos_por_busqueda(&self) -> f64 {
+        match self.organizacion {
+            OrganizacionTabla::Jerarquica => self.tabla_paginas.niveles() as f64,
+            organizacion => self.coste_busqueda(organizacion).media(),
         }
+    }
+
+    /// Busca un proceso activo por su PID
+    pub fn proceso(&self, pid: usize) -> Option<&Proceso> {
+        self.procesos.iter().find(|p| p.pid == pid)
+    }
+
+    /// Nombre de la política de reemplazo activa
+    pub fn nombre_algoritmo(&self) -> &str {
+        self.politica.nombre()
+    }
+
+    /// Registra (o reemplaza) una política seleccionable por nombre. Una
+    /// política externa con el nombre de una incluida tiene prioridad sobre ella.
+    pub fn registrar_politica(&mut self, nombre: &str, fabrica: FabricaPolitica) {
+        self.registro_politicas.retain(|(n, _)| n != nombre);
+        self.registro_politicas.push((nombre.to_string(), fabrica));
+    }
 
-        // Limpiar páginas del proceso en Swap
-        let mut nueva_cola_swap = VecDeque::new();
-        while let Some((pid, pagina)) = self.cola_swap.pop_front() {
-            if pid != pid_victima {
-                nueva_cola_swap.push_back((pid, pagina));
+    /// Nombres de las políticas seleccionables: primero las incluidas, después
+    /// las externas en orden de registro
+    pub fn politicas_registradas(&self) -> Vec<&str> {
+        let mut nombres: Vec<&str> = AlgoritmoReemplazo::TODOS
+            .iter()
+            .map(|alg| alg.nombre())
+            .collect();
+        for (nombre, _) in &self.registro_politicas {
+            if !nombres.iter().any(|n| n.eq_ignore_ascii_case(nombre)) {
+                nombres.push(nombre);
             }
         }
-        self.cola_swap = nueva_cola_swap;
+        nombres
+    }
 
-        // Eliminar proceso de la lista
-        self.procesos.retain(|p| p.pid != pid_victima);
-        self.procesos_finalizados += 1;
+    /// Activa una política buscándola por nombre (sin distinguir mayúsculas)
+    pub fn seleccionar_politica(&mut self, nombre: &str) -> Result<(), ErrorMemoria> {
+        let politica = self
+            .registro_politicas
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(nombre))
+            .map(|(_, fabrica)| fabrica())
+            .or_else(|| {
+                AlgoritmoReemplazo::desde_nombre(nombre)
+                    .map(|alg| alg.crear_politica(&self.parametros))
+            })
+            .ok_or_else(|| ErrorMemoria::PoliticaDesconocida(nombre.to_string()))?;
+        self.establecer_politica(politica);
+        Ok(())
+    }
+
+    /// Cambia los parámetros de las políticas incluidas; si la activa es una
+    /// de ellas se reconstruye con los nuevos valores
+    pub fn establecer_parametros(&mut self, parametros: ParametrosPoliticas) {
+        self.parametros = parametros;
+        let nombre = self.politica.nombre().to_string();
+        let externa = self
+            .registro_politicas
+            .iter()
+            .any(|(n, _)| n.eq_ignore_ascii_case(&nombre));
+        if let (false, Some(alg)) = (externa, AlgoritmoReemplazo::desde_nombre(&nombre)) {
+            let politica = alg.crear_politica(&self.parametros);
+            self.instalar_politica(politica);
+        }
+    }
 
+    /// Sustituye la política activa y lo registra en el log
+    pub fn establecer_politica(&mut self, politica: Box<dyn PoliticaReemplazo>) {
+        self.instalar_politica(politica);
         self.registrar_log(&format!(
-            "[TERM] Proceso P{} terminado y liberado",
-            pid_victima
+            "[CONFIG] Algoritmo cambiado a {}",
+            self.politica.nombre()
         ));
     }
 
-    /// Carga una página en memoria RAM (con swapping si es necesario)
-    fn cargar_pagina(&mut self, pid: usize, idx_pagina: usize) -> Result<(), String> {
-        self.accesos_totales += 1;
-        let tiempo_acceso = self.accesos_totales as u64; // Capturar antes del préstamo mutable
+    /// Crea de nuevo la política activa con su fábrica (registrada o
+    /// incluida) y la instala sobre los marcos actuales. Si no tiene fábrica
+    /// se conserva tal cual.
+    fn reconstruir_politica(&mut self) {
+        let nombre = self.politica.nombre().to_string();
+        let politica = self
+            .registro_politicas
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(&nombre))
+            .map(|(_, fabrica)| fabrica())
+            .or_else(|| {
+                AlgoritmoReemplazo::desde_nombre(&nombre)
+                    .map(|alg| alg.crear_politica(&self.parametros))
+            });
+        if let Some(politica) = politica {
+            self.instalar_politica(politica);
+        }
+    }
 
-        // Buscar marco libre en RAM
-        let marco_libre = self
+    /// La nueva política recibe los marcos ocupados como cargas, en orden de
+    /// último uso, para partir del estado actual
+    fn instalar_politica(&mut self, mut politica: Box<dyn PoliticaReemplazo>) {
+        let mut ocupados: Vec<(u64, usize)> = self
             .marcos_ram
             .iter()
-            .position(|marco| marco.proceso_id.is_none());
+            .filter_map(|marco| {
+                let (pid, pg) = (marco.proceso_id?, marco.pagina_id?);
+                let uso = self
+                    .proceso(pid)
+                    .and_then(|p| p.paginas.iter().find(|page| page.id == pg))
+                    .map_or(0, |page| page.ultimo_uso);
+                Some((uso, marco.id))
+            })
+            .collect();
+        ocupados.sort();
+        for (_, idx) in ocupados {
+            politica.al_cargar(&self.marcos_ram[idx]);
+        }
 
-        let idx_objetivo = match marco_libre {
-            Some(i) => {
-                // Hay espacio libre
-                i
-            }
-            None => {
-                // RAM llena, activar algoritmo de reemplazo (Requisito E)
-                self.fallos_pagina += 1;
-                self.reemplazar_pagina()?
-            }
-        };
+        self.politica = politica;
+    }
 
-        // Asignar página al marco
-        self.marcos_ram[idx_objetivo].proceso_id = Some(pid);
-        self.marcos_ram[idx_objetivo].pagina_id = Some(idx_pagina);
+    /// Cambia el alcance del reemplazo y el reparto inicial de cuotas. PFF
+    /// solo tiene sentido con cuotas, así que otro alcance lo desactiva.
+    pub fn establecer_alcance(&mut self, alcance: AlcanceReemplazo, asignacion: AsignacionInicial) {
+        self.alcance = alcance;
+        self.asignacion = asignacion;
+        let pff_desactivado = alcance != AlcanceReemplazo::Cuota && self.pff.take().is_some();
+        self.repartir_cuotas();
+        self.registrar_log(&format!(
+            "[CONFIG] Alcance de reemplazo: {} (reparto {})",
+            alcance.nombre(),
+            asignacion.nombre()
+        ));
+        if pff_desactivado {
+            self.registrar_log(
+                "[CONFIG] Asignación PFF desactivada: solo funciona con alcance por cuota",
+            );
+        }
+    }
 
-        // Actualizar tabla de páginas del proceso
-        if let Some(pagina) = self.obtener_info_pagina(pid, idx_pagina) {
-            pagina.marco_id = Some(idx_objetivo);
-            pagina.referenciada = true;
-            pagina.ultimo_uso = tiempo_acceso; // Usar valor capturado
+    /// Cambia la organización de la tabla de páginas (niveles y bits de cada
+    /// uno). Falla si algún proceso activo no cabe en el nuevo espacio virtual.
+    pub fn establecer_tabla_paginas(
+        &mut self,
+        tabla: ParametrosTablaPaginas,
+    ) -> Result<(), ErrorMemoria> {
+        let maximo = tabla.paginas_direccionables();
+        if let Some(paginas) = self
+            .procesos
+            .iter()
+            .map(|p| p.paginas.len())
+            .find(|&n| n > maximo)
+        {
+            return Err(ErrorMemoria::EspacioVirtualInsuficiente { paginas, maximo });
         }
+        self.registrar_log(&format!(
+            "[TABLAS] Tabla de páginas de {} nivel(es), bits {:?}",
+            tabla.niveles(),
+            tabla.bits_por_nivel
+        ));
+        self.tabla_paginas = tabla;
+        Ok(())
+    }
 
-        // Avanzar puntero si es FIFO
-        if self.algoritmo == AlgoritmoReemplazo::FIFO {
-            self.avanzar_reloj();
+    /// Cambia entre paginación, particiones variables y buddy. Los procesos activos
+    /// no pueden pasar de un modo a otro, así que se terminan todos.
+    pub fn establecer_modo(&mut self, modo: ModoMemoria) {
+        let pids: Vec<usize> = self.procesos.iter().map(|p| p.pid).collect();
+        for pid in &pids {
+            let _ = self.matar_proceso(*pid);
         }
+        // Los slabs viven en marcos de paginación: el núcleo los devuelve todos
+        for marco in self.slab.vaciar() {
+            self.marcos_ram[marco].nucleo = false;
+        }
+        self.modo = modo;
+        self.registrar_log(&format!(
+            "[MODO] Memoria en modo {} ({} procesos terminados)",
+            modo.nombre(),
+            pids.len()
+        ));
+    }
 
-        Ok(())
+    /// Cambia el criterio de colocación del modo contiguo
+    pub fn establecer_estrategia(&mut self, estrategia: EstrategiaAjuste) {
+        self.contigua.estrategia = estrategia;
+        self.registrar_log(&format!(
+            "[CONTIGUA] Estrategia de colocación: {}",
+            estrategia.nombre()
+        ));
     }
 
-    /// Selecciona y reemplaza una página víctima usando el algoritmo configurado (Requisito E)
-    fn reemplazar_pagina(&mut self) -> Result<usize, String> {
-        let idx_victima = match self.algoritmo {
-            // FIFO: Reemplaza la página más antigua (puntero circular)
-            AlgoritmoReemplazo::FIFO => self.puntero_reloj,
-
-            // Algoritmo del Reloj: Segunda oportunidad
-            AlgoritmoReemplazo::Reloj => {
-                loop {
-                    let idx = self.puntero_reloj;
-                    let (pid, pg) = {
-                        let marco = &self.marcos_ram[idx];
-                        (marco.proceso_id.unwrap(), marco.pagina_id.unwrap())
-                    };
-
-                    let mut es_victima = false;
-                    if let Some(pagina) = self.obtener_info_pagina(pid, pg) {
-                        if pagina.referenciada {
-                            // Dar segunda oportunidad
-                            pagina.referenciada = false;
-                        } else {
-                            // No ha sido referenciada, es víctima
-                            es_victima = true;
-                        }
-                    }
-
-                    if es_victima {
-                        break idx;
-                    } else {
-                        self.avanzar_reloj();
-                    }
-                }
-            }
+    /// Cambia la tabla que se recorre tras un fallo de TLB. Las tres se
+    /// mantienen siempre al día, así que el cambio es inmediato.
+    pub fn establecer_organizacion(&mut self, organizacion: OrganizacionTabla) {
+        self.organizacion = organizacion;
+        self.registrar_log(&format!(
+            "[TABLAS] Organización de la tabla de páginas: {}",
+            organizacion.nombre()
+        ));
+    }
 
-            // LRU: Reemplaza la página menos recientemente usada
-            AlgoritmoReemplazo::LRU => {
-                let mut min_uso = u64::MAX;
-                let mut idx_victima = 0;
-
-                for (idx, marco) in self.marcos_ram.iter().enumerate() {
-                    if let (Some(pid), Some(pg)) = (marco.proceso_id, marco.pagina_id) {
-                        if let Some(pagina) = self
-                            .procesos
-                            .iter()
-                            .find(|p| p.pid == pid)
-                            .and_then(|p| p.paginas.iter().find(|page| page.id == pg))
-                        {
-                            if pagina.ultimo_uso < min_uso {
-                                min_uso = pagina.ultimo_uso;
-                                idx_victima = idx;
-                            }
-                        }
-                    }
-                }
-                idx_victima
-            }
-        };
+    /// Recorre las tablas de páginas tras un fallo de TLB. Mide el coste de
+    /// las tres organizaciones para compararlas, pero solo la activa cuenta
+    /// en `referencias_tabla` y decide el marco. `en_tabla` es el marco que
+    /// indica la tabla jerárquica del proceso; invertida y hash resuelven el
+    /// marco con sus propias entradas.
+    fn recorrer_tablas(
+        &mut self,
+        pid: usize,
+        idx_pagina: usize,
+        en_tabla: Option<usize>,
+    ) -> Option<usize> {
+        let niveles = self.tabla_paginas.niveles();
+        let (en_invertida, accesos_invertida) = self.tabla_invertida.buscar(pid, idx_pagina);
+        let (en_hash, accesos_hash) = self.tabla_hash.buscar(pid, idx_pagina);
 
-        // Obtener información de la página víctima
-        let (pid_victima, pag_victima) = {
-            let marco = &self.marcos_ram[idx_victima];
-            (marco.proceso_id.unwrap(), marco.pagina_id.unwrap())
+        for (organizacion, accesos) in [
+            (OrganizacionTabla::Jerarquica, niveles),
+            (OrganizacionTabla::Invertida, accesos_invertida),
+            (OrganizacionTabla::Hash, accesos_hash),
+        ] {
+            self.costes_busqueda[organizacion.indice()].registrar(accesos);
+        }
+
+        let (marco, accesos) = match self.organizacion {
+            OrganizacionTabla::Jerarquica => (en_tabla, niveles),
+            OrganizacionTabla::Invertida => (en_invertida, accesos_invertida),
+            OrganizacionTabla::Hash => (en_hash, accesos_hash),
         };
+        self.referencias_tabla += accesos;
+        marco
+    }
 
-        // Actualizar tabla de páginas (marcar como no presente en RAM)
-        if let Some(pagina) = self.obtener_info_pagina(pid_victima, pag_victima) {
-            pagina.marco_id = None;
+    /// Reconstruye la TLB con otra geometría (empieza vacía y sin contadores)
+    pub fn establecer_tlb(&mut self, parametros: ParametrosTlb) {
+        self.tlb = Tlb::new(parametros);
+        self.registrar_log(&format!(
+            "[TLB] {} entradas, {} vías, reemplazo {}, modo {}",
+            parametros.entradas,
+            parametros.vias_efectivas(),
+            parametros.reemplazo.nombre(),
+            parametros.modo.nombre()
+        ));
+    }
+
+    /// Activa o desactiva el ajuste de cuotas por PFF. Activarlo pasa el
+    /// alcance a cuota; las cuotas parten del reparto inicial configurado.
+    pub fn establecer_pff(&mut self, pff: Option<ParametrosPff>) {
+        self.pff = pff;
+        if pff.is_some() {
+            self.alcance = AlcanceReemplazo::Cuota;
         }
+        self.repartir_cuotas();
+        self.registrar_log(&match pff {
+            Some(p) => format!(
+                "[CONFIG] Asignación PFF activa (sube > {:.0}%, baja < {:.0}%, ventana {})",
+                p.umbral_superior * 100.0,
+                p.umbral_inferior * 100.0,
+                p.ventana
+            ),
+            None => "[CONFIG] Asignación PFF desactivada".to_string(),
+        });
+    }
+
+    /// Cuota de un proceso con `peso` sobre `peso_total`, entre 1 marco y
+    /// todas sus páginas
+    fn calcular_cuota(&self, peso: usize, peso_total: usize, paginas: usize) -> usize {
+        let reparto = self.marcos_ram.len() * peso / peso_total.max(1);
+        reparto.min(paginas).max(1)
+    }
 
-        // Verificar capacidad del Swap
-        if self.cola_swap.len() >= 50 {
-            return Err("[ERROR] Swap lleno (50 páginas MAX)".to_string());
+    /// Recalcula las cuotas de todos los procesos según el reparto inicial
+    /// (sin cuotas si el alcance no es por cuota)
+    fn repartir_cuotas(&mut self) {
+        let asignacion = self.asignacion;
+        let peso_total: usize = self.procesos.iter().map(|p| asignacion.peso(p)).sum();
+        let cuotas: Vec<Option<usize>> = self
+            .procesos
+            .iter()
+            .map(|p| {
+                (self.alcance == AlcanceReemplazo::Cuota)
+                    .then(|| self.calcular_cuota(asignacion.peso(p), peso_total, p.paginas.len()))
+            })
+            .collect();
+        for (proceso, cuota) in self.procesos.iter_mut().zip(cuotas) {
+            proceso.cuota_marcos = cuota;
+            proceso.fallos_ventana = 0;
+            proceso.referencias_ventana = 0;
         }
+    }
 
-        // Mover página a Swap
-        self.cola_swap.push_back((pid_victima, pag_victima));
-        self.swaps_realizados += 1;
+    /// Marcos de RAM ocupados por un proceso
+    pub fn marcos_de(&self, pid: usize) -> usize {
+        self.marcos_ram
+            .iter()
+            .filter(|marco| marco.proceso_id == Some(pid))
+            .count()
+    }
 
-        self.registrar_log(&format!(
-            "[SWAP] Proceso P{} Página {} movida a Swap (Marco {} liberado)",
-            pid_victima, pag_victima, idx_victima
-        ));
+    /// Traduce una dirección virtual (en bytes) del proceso a dirección
+    /// física: la divide en página y desplazamiento según `tamaño_pagina_kb`,
+    /// consulta la TLB y la tabla de páginas y, si la página no está en RAM, atiende el
+    /// fallo como cualquier otro acceso antes de componer la dirección física.
+    pub fn traducir_direccion(
+        &mut self,
+        pid: usize,
+        direccion_virtual: usize,
+    ) -> Result<Traduccion, ErrorMemoria> {
+        if self.modo != ModoMemoria::Paginacion {
+            return self.traducir_contigua(pid, direccion_virtual);
+        }
+        let tamaño_pagina = self.tamaño_pagina_kb * 1024;
+        let pagina = direccion_virtual / tamaño_pagina;
+        let desplazamiento = direccion_virtual % tamaño_pagina;
+
+        let mut pasos = vec![
+            format!(
+                "Dirección virtual 0x{:X} ({}) del proceso P{}",
+                direccion_virtual, direccion_virtual, pid
+            ),
+            format!(
+                "Página de {} bytes: página {} = 0x{:X} / {}, desplazamiento 0x{:X}",
+                tamaño_pagina, pagina, direccion_virtual, tamaño_pagina, desplazamiento
+            ),
+        ];
+
+        let proceso = self.proceso(pid).ok_or(ErrorMemoria::PidDesconocido(pid))?;
+        // La última página puede ser parcial: el límite es el tamaño del proceso
+        let limite = proceso.tamaño_kb * 1024;
+        if direccion_virtual >= limite {
+            return Err(ErrorMemoria::FueraDeLimite {
+                pid,
+                direccion: direccion_virtual,
+                limite,
+            });
+        }
+        let marco_previo = proceso
+            .paginas
+            .iter()
+            .find(|p| p.id == pagina)
+            .ok_or(ErrorMemoria::PaginaFueraDeRango { pid, pagina })?
+            .marco_id;
+        let total_paginas = proceso.paginas.len();
+
+        let paso_tabla = match marco_previo {
+            Some(marco) => format!(
+                "Tabla de páginas de P{} ({} entradas): página {} presente en el marco {}",
+                pid, total_paginas, pagina, marco
+            ),
+            None => format!(
+                "Tabla de páginas de P{} ({} entradas): página {} no presente -> fallo de página{}",
+                pid,
+                total_paginas,
+                pagina,
+                if self.cola_swap.contains(&(pid, pagina)) {
+                    " (está en Swap)"
+                } else {
+                    ""
+                }
+            ),
+        };
+
+        let (aciertos_previos, vaciados_previos) = (self.tlb.aciertos, self.tlb.vaciados);
+        let referencias_previas = self.referencias_tabla;
+        self.acceder_pagina(pid, pagina, TipoAcceso::Lectura)?;
+        let acierto_tlb = self.tlb.aciertos > aciertos_previos;
+        let accesos_tabla = self.referencias_tabla - referencias_previas;
+
+        if self.tlb.vaciados > vaciados_previos {
+            pasos.push(format!("Cambio de contexto a P{}: TLB vaciada", pid));
+        }
+        if acierto_tlb {
+            pasos.push(format!(
+                "TLB: acierto para (P{}, página {}), sin consultar la tabla de páginas",
+                pid, pagina
+            ));
+        } else {
+            pasos.push(format!("TLB: fallo para (P{}, página {})", pid, pagina));
+            let niveles = self.tabla_paginas.niveles();
+            match self.organizacion {
+                OrganizacionTabla::Jerarquica => {}
+                OrganizacionTabla::Invertida => pasos.push(format!(
+                    "Tabla invertida: ancla hash(P{}, página {}) + {} entrada(s) de la cadena = {} accesos",
+                    pid,
+                    pagina,
+                    accesos_tabla.saturating_sub(1),
+                    accesos_tabla
+                )),
+                OrganizacionTabla::Hash => pasos.push(format!(
+                    "Tabla hash ({} cubetas): cubeta + {} nodo(s) de la cadena = {} accesos",
+                    self.tabla_hash.cubetas(),
+                    accesos_tabla.saturating_sub(1),
+                    accesos_tabla
+                )),
+            }
+            if self.organizacion == OrganizacionTabla::Jerarquica && niveles > 1 {
+                let indices = self.tabla_paginas.indices(pagina);
+                for (nivel, (indice, bits)) in indices
+                    .iter()
+                    .zip(&self.tabla_paginas.bits_por_nivel)
+                    .enumerate()
+                {
+                    pasos.push(format!(
+                        "Nivel {} de {} ({} bits): entrada {} (1 acceso a memoria)",
+                        nivel + 1,
+                        niveles,
+                        bits,
+                        indice
+                    ));
+                }
+            }
+            match (self.organizacion, marco_previo) {
+                (OrganizacionTabla::Invertida, Some(marco)) => pasos.push(format!(
+                    "Entrada encontrada: la página está en el marco {}",
+                    marco
+                )),
+                (OrganizacionTabla::Invertida, None) => pasos
+                    .push("Sin entrada: la página no está en RAM -> fallo de página".to_string()),
+                _ => pasos.push(paso_tabla),
+            }
+        }
+
+        let marco = self
+            .proceso(pid)
+            .and_then(|p| p.paginas.iter().find(|page| page.id == pagina))
+            .and_then(|page| page.marco_id)
+            .ok_or(ErrorMemoria::PaginaFueraDeRango { pid, pagina })?;
+        if marco_previo.is_none() {
+            pasos.push(format!("Página {} cargada en el marco {}", pagina, marco));
+        }
+
+        let direccion_fisica = marco * tamaño_pagina + desplazamiento;
+        pasos.push(format!(
+            "Dirección física = marco {} x {} + 0x{:X} = 0x{:X} ({})",
+            marco, tamaño_pagina, desplazamiento, direccion_fisica, direccion_fisica
+        ));
+
+        Ok(Traduccion {
+            pid,
+            direccion_virtual,
+            pagina,
+            desplazamiento,
+            acierto_tlb,
+            fallo: marco_previo.is_none(),
+            marco,
+            direccion_fisica,
+            pasos,
+        })
+    }
+
+    /// Traducción con registros base y límite: la dirección se compara con el
+    /// tamaño del proceso y se suma a la dirección de inicio de su bloque (en
+    /// buddy el límite no cubre el relleno hasta la potencia de dos)
+    fn traducir_contigua(
+        &self,
+        pid: usize,
+        direccion_virtual: usize,
+    ) -> Result<Traduccion, ErrorMemoria> {
+        let bloque = match self.modo {
+            ModoMemoria::Buddy => self
+                .buddy
+                .bloque_de(pid)
+                .map(|(inicio, bloque)| (inicio, bloque.solicitado)),
+            _ => self.contigua.bloque_de(pid).map(|b| (b.inicio, b.tamaño)),
+        };
+        let (inicio, tamaño) = bloque.ok_or(ErrorMemoria::PidDesconocido(pid))?;
+        let (base, limite) = (inicio * 1024, tamaño * 1024);
+        if direccion_virtual >= limite {
+            return Err(ErrorMemoria::FueraDeLimite {
+                pid,
+                direccion: direccion_virtual,
+                limite,
+            });
+        }
+
+        let direccion_fisica = base + direccion_virtual;
+        let pasos = vec![
+            format!(
+                "Dirección virtual 0x{:X} ({}) del proceso P{}",
+                direccion_virtual, direccion_virtual, pid
+            ),
+            format!(
+                "Registro límite = {} bytes: 0x{:X} < 0x{:X}, dirección válida",
+                limite, direccion_virtual, limite
+            ),
+            format!(
+                "Dirección física = base 0x{:X} + 0x{:X} = 0x{:X} ({})",
+                base, direccion_virtual, direccion_fisica, direccion_fisica
+            ),
+        ];
+
+        Ok(Traduccion {
+            pid,
+            direccion_virtual,
+            pagina: 0,
+            desplazamiento: direccion_virtual,
+            acierto_tlb: false,
+            fallo: false,
+            marco: 0,
+            direccion_fisica,
+            pasos,
+        })
+    }
+
+    /// Comprueba que RAM, tablas de páginas y Swap cuentan lo mismo: cada
+    /// marco ocupado corresponde exactamente a una página con ese `marco_id`,
+    /// cada página presente apunta a un marco que la contiene, ninguna página
+    /// en Swap figura a la vez en RAM y las tablas invertida y hash coinciden
+    /// con ambas. Devuelve las inconsistencias halladas.
+    pub fn verificar_consistencia(&self) -> Vec<String> {
+        let mut errores = Vec::new();
+
+        for marco in &self.marcos_ram {
+            let (pid, pg) = match (marco.proceso_id, marco.pagina_id) {
+                (None, None) => continue,
+                (Some(pid), Some(pg)) => (pid, pg),
+                _ => {
+                    errores.push(format!("Marco {} ocupado a medias", marco.id));
+                    continue;
+                }
+            };
+            let apuntan: Vec<(usize, usize)> = self
+                .procesos
+                .iter()
+                .flat_map(|p| p.paginas.iter().map(move |page| (p.pid, page)))
+                .filter(|(_, page)| page.marco_id == Some(marco.id))
+                .map(|(pid, page)| (pid, page.id))
+                .collect();
+            if apuntan != [(pid, pg)] {
+                errores.push(format!(
+                    "Marco {} contiene P{} pág {} pero lo referencian {:?}",
+                    marco.id, pid, pg, apuntan
+                ));
+            }
+        }
+
+        for proceso in &self.procesos {
+            for pagina in &proceso.paginas {
+                let Some(idx_marco) = pagina.marco_id else {
+                    continue;
+                };
+                let contenido = self
+                    .marcos_ram
+                    .get(idx_marco)
+                    .map(|m| (m.proceso_id, m.pagina_id));
+                if contenido != Some((Some(proceso.pid), Some(pagina.id))) {
+                    errores.push(format!(
+                        "P{} pág {} apunta al marco {} que no la contiene",
+                        proceso.pid, pagina.id, idx_marco
+                    ));
+                }
+                if self.cola_swap.contains(&(proceso.pid, pagina.id)) {
+                    errores.push(format!(
+                        "P{} pág {} está a la vez en RAM y en Swap",
+                        proceso.pid, pagina.id
+                    ));
+                }
+            }
+        }
+
+        // Las tablas invertida y hash deben describir lo mismo que la RAM y
+        // las tablas de cada proceso
+        for marco in &self.marcos_ram {
+            let esperado = marco.proceso_id.zip(marco.pagina_id);
+            if self.tabla_invertida.pagina_en(marco.id) != esperado {
+                errores.push(format!(
+                    "Tabla invertida desincronizada en el marco {}",
+                    marco.id
+                ));
+            } else if let Some((pid, pg)) = esperado {
+                if self.tabla_invertida.buscar(pid, pg).0 != Some(marco.id) {
+                    errores.push(format!(
+                        "P{} pág {} no es alcanzable desde su ancla en la tabla invertida",
+                        pid, pg
+                    ));
+                }
+            }
+        }
+        let total_paginas: usize = self.procesos.iter().map(|p| p.paginas.len()).sum();
+        let sin_nodo = self
+            .procesos
+            .iter()
+            .flat_map(|p| p.paginas.iter().map(move |page| (p.pid, page.id)))
+            .filter(|&(pid, pg)| !self.tabla_hash.contiene(pid, pg))
+            .count();
+        if sin_nodo > 0 || self.tabla_hash.nodos() != total_paginas {
+            errores.push(format!(
+                "Tabla hash con {} nodos para {} páginas ({} sin nodo)",
+                self.tabla_hash.nodos(),
+                total_paginas,
+                sin_nodo
+            ));
+        }
+        for proceso in &self.procesos {
+            for pagina in &proceso.paginas {
+                if self.tabla_hash.buscar(proceso.pid, pagina.id).0 != pagina.marco_id {
+                    errores.push(format!(
+                        "P{} pág {}: la tabla hash no indica su marco",
+                        proceso.pid, pagina.id
+                    ));
+                }
+            }
+        }
+
+        // Los marcos del núcleo son exactamente los de los slabs
+        let mut de_slabs: Vec<usize> = self.slab.marcos().collect();
+        de_slabs.sort_unstable();
+        let del_nucleo: Vec<usize> = self
+            .marcos_ram
+            .iter()
+            .filter(|m| m.nucleo)
+            .map(|m| m.id)
+            .collect();
+        if de_slabs != del_nucleo {
+            errores.push(format!(
+                "Marcos del núcleo {:?} no coinciden con los slabs {:?}",
+                del_nucleo, de_slabs
+            ));
+        }
+        for marco in self.marcos_ram.iter().filter(|m| m.nucleo) {
+            if marco.proceso_id.is_some() {
+                errores.push(format!(
+                    "Marco {} del núcleo ocupado también por P{}",
+                    marco.id,
+                    marco.proceso_id.unwrap_or(0)
+                ));
+            }
+        }
+
+        // En buddy cada proceso tiene su bloque y bloques libres y ocupados
+        // cubren toda la memoria gestionada
+        if self.modo == ModoMemoria::Buddy {
+            for proceso in &self.procesos {
+                if self.buddy.bloque_de(proceso.pid).is_none() {
+                    errores.push(format!("P{} no tiene bloque buddy", proceso.pid));
+                }
+            }
+            let ocupado: usize = self.buddy.asignados().map(|(_, b)| b.tamaño()).sum();
+            if ocupado + self.buddy.libre_total() != self.buddy.tamaño_kb {
+                errores.push(format!(
+                    "Buddy: {}KB ocupados + {}KB libres no suman {}KB",
+                    ocupado,
+                    self.buddy.libre_total(),
+                    self.buddy.tamaño_kb
+                ));
+            }
+        }
+
+        errores
+    }
+
+    /// Registra una referencia del proceso en su ventana PFF y, al cerrarla,
+    /// ajusta la cuota según la tasa de fallos medida
+    fn medir_pff(&mut self, pid: usize, fallo: bool) {
+        let Some(pff) = self.pff else {
+            return;
+        };
+        let total_marcos = self.marcos_ram.len().max(1);
+        let Some(proceso) = self.procesos.iter_mut().find(|p| p.pid == pid) else {
+            return;
+        };
+        proceso.referencias_ventana += 1;
+        if fallo {
+            proceso.fallos_ventana += 1;
+        }
+        if proceso.referencias_ventana < pff.ventana.max(1) {
+            return;
+        }
+
+        let tasa = proceso.fallos_ventana as f64 / proceso.referencias_ventana as f64;
+        proceso.fallos_ventana = 0;
+        proceso.referencias_ventana = 0;
+        let cuota = proceso.cuota_marcos.unwrap_or(1);
+        let maximo = proceso.paginas.len().min(total_marcos).max(1);
+        let nueva = if tasa > pff.umbral_superior {
+            (cuota + 1).min(maximo)
+        } else if tasa < pff.umbral_inferior {
+            cuota.saturating_sub(1).max(1)
+        } else {
+            cuota
+        };
+        proceso.cuota_marcos = Some(nueva);
+
+        if nueva != cuota {
+            self.registrar_log(&format!(
+                "[PFF] P{} tasa de fallos {:.0}%: cuota {} -> {} marcos",
+                pid,
+                tasa * 100.0,
+                cuota,
+                nueva
+            ));
+        }
+    }
+
+    /// Obtiene referencia mutable a una página específica de un proceso
+    fn obtener_info_pagina(&mut self, pid: usize, idx_pagina: usize) -> Option<&mut Pagina> {
+        if let Some(proceso) = self.procesos.iter_mut().find(|p| p.pid == pid) {
+            proceso.paginas.iter_mut().find(|p| p.id == idx_pagina)
+        } else {
+            None
+        }
+    }
+
+    /// Asigna un nuevo proceso al sistema (Requisito B y C). La admisión es
+    /// todo o nada: antes de tocar la memoria se comprueba que el Swap puede
+    /// absorber todos los desalojos que provocará la precarga, y si aun así
+    /// la carga falla se deshace por completo.
+    pub fn asignar_proceso(&mut self, mut proceso: Proceso) -> Result<(), ErrorMemoria> {
+        let pid = proceso.pid;
+        let paginas_necesarias =
+            (proceso.tamaño_kb as f64 / self.tamaño_pagina_kb as f64).ceil() as usize;
+
+        if self.proceso(pid).is_some() {
+            return Err(ErrorMemoria::PidDuplicado(pid));
+        }
+        if self.procesos.len() >= self.max_procesos {
+            return Err(ErrorMemoria::LimiteProcesos {
+                maximo: self.max_procesos,
+            });
+        }
+        match self.modo {
+            ModoMemoria::Paginacion => {}
+            ModoMemoria::Contigua => return self.asignar_contiguo(proceso),
+            ModoMemoria::Buddy => return self.asignar_buddy(proceso),
+        }
+        if self.marcos_ram.is_empty() && paginas_necesarias > 0 {
+            return Err(ErrorMemoria::SinMarcos);
+        }
+        if paginas_necesarias > self.tabla_paginas.paginas_direccionables() {
+            return Err(ErrorMemoria::EspacioVirtualInsuficiente {
+                paginas: paginas_necesarias,
+                maximo: self.tabla_paginas.paginas_direccionables(),
+            });
+        }
+
+        // Crear tabla de páginas del proceso
+        for i in 0..paginas_necesarias {
+            proceso.paginas.push(Pagina {
+                id: i,
+                marco_id: None,
+                referenciada: true,
+                ultimo_uso: 0,
+                contador_accesos: 0,
+                modificada: false,
+                edad: 0,
+                ultimo_uso_virtual: None,
+            });
+        }
+
+        // Cuota inicial del proceso según el reparto configurado
+        let asignacion = self.asignacion;
+        let peso = asignacion.peso(&proceso);
+        let peso_total = peso
+            + self
+                .procesos
+                .iter()
+                .map(|p| asignacion.peso(p))
+                .sum::<usize>();
+        let cuota = self.calcular_cuota(peso, peso_total, paginas_necesarias);
+
+        // Cada página que no entra en un marco libre (o por encima de la cuota)
+        // desaloja otra hacia Swap
+        let libres = self.marcos_disponibles();
+        let sin_desalojo = match self.alcance {
+            AlcanceReemplazo::Cuota => libres.min(cuota),
+            AlcanceReemplazo::Global | AlcanceReemplazo::Local => libres,
+        };
+        let desalojos = paginas_necesarias.saturating_sub(sin_desalojo);
+        let huecos_swap = self.capacidad_swap.saturating_sub(self.cola_swap.len());
+        if desalojos > huecos_swap {
+            return Err(ErrorMemoria::SwapInsuficiente {
+                desalojos,
+                libres: huecos_swap,
+            });
+        }
+        self.cargar_proceso(proceso, cuota)
+    }
+
+    /// Registra el proceso ya paginado y precarga todas sus páginas. Si una
+    /// carga falla se restaura el estado previo a la admisión completo.
+    fn cargar_proceso(&mut self, mut proceso: Proceso, cuota: usize) -> Result<(), ErrorMemoria> {
+        let pid = proceso.pid;
+        let paginas_necesarias = proceso.paginas.len();
+
+        // Estado previo para deshacer la admisión si la carga falla
+        let marcos_previos = self.marcos_ram.clone();
+        let swap_previo = self.cola_swap.clone();
+        let procesos_previos = self.procesos.clone();
+        let slab_previo = self.slab.clone();
+        let hash_previa = self.tabla_hash.clone();
+        let politica_previa = self.politica.instantanea();
+        let logs_previos = self.logs.clone();
+        let contadores_previos = (
+            self.fallos_pagina,
+            self.accesos_totales,
+            self.swaps_salida,
+            self.escrituras_swap,
+            self.robos_marcos,
+        );
+
+        // Registrar el proceso antes de cargar sus páginas: la tabla de
+        // páginas tiene que estar viva para que `cargar_pagina` la actualice
+        proceso.cuota_marcos = (self.alcance == AlcanceReemplazo::Cuota).then_some(cuota);
+        self.procesos.push(proceso);
+
+        // Intentar cargar todas las páginas del proceso
+        for idx_pagina in 0..paginas_necesarias {
+            // Precarga: solo cuenta como fallo si obliga a reemplazar
+            if self.marcos_disponibles() == 0 {
+                self.fallos_pagina += 1;
+                if let Some(proceso) = self.procesos.iter_mut().find(|p| p.pid == pid) {
+                    proceso.fallos_pagina += 1;
+                }
+            }
+            if let Err(error) = self.cargar_pagina(pid, idx_pagina) {
+                // Devolver los marcos del proceso y restaurar las víctimas
+                self.marcos_ram = marcos_previos;
+                self.cola_swap = swap_previo;
+                self.procesos = procesos_previos;
+                self.slab = slab_previo;
+                self.tabla_hash = hash_previa;
+                // Los desalojos deshechos no deben quedar en el registro
+                self.logs = logs_previos;
+                // La política vuelve a su estado previo; si no sabe copiarse,
+                // se reconstruye sobre los marcos restaurados
+                match politica_previa {
+                    Some(politica) => self.politica = politica,
+                    None => self.reconstruir_politica(),
+                }
+                self.tabla_invertida.reconstruir(
+                    self.marcos_ram
+                        .iter()
+                        .filter_map(|m| Some((m.id, m.proceso_id?, m.pagina_id?))),
+                );
+                (
+                    self.fallos_pagina,
+                    self.accesos_totales,
+                    self.swaps_salida,
+                    self.escrituras_swap,
+                    self.robos_marcos,
+                ) = contadores_previos;
+                return Err(error);
+            }
+        }
+
+        let marcos_cargados: Vec<Option<usize>> = self
+            .proceso(pid)
+            .map(|p| p.paginas.iter().map(|pg| pg.marco_id).collect())
+            .unwrap_or_default();
+        for (idx_pagina, marco) in marcos_cargados.into_iter().enumerate() {
+            self.tabla_hash.insertar(pid, idx_pagina, marco);
+        }
+        self.procesos_creados += 1;
+        let (nombre, tamaño_kb) = self
+            .proceso(pid)
+            .map_or((String::new(), 0), |p| (p.nombre.clone(), p.tamaño_kb));
+        self.registrar_log(&format!(
+            "[NUEVO] Proceso P{} '{}' creado ({}KB, {} páginas)",
+            pid, nombre, tamaño_kb, paginas_necesarias
+        ));
+
+        // Sin PFF las cuotas son fijas: se vuelven a repartir entre todos
+        if self.pff.is_none() {
+            self.repartir_cuotas();
+        }
+        Ok(())
+    }
+
+    /// Admisión en modo contiguo: el proceso recibe un único bloque de
+    /// `tamaño_kb` (sin páginas, así que no genera referencias)
+    fn asignar_contiguo(&mut self, proceso: Proceso) -> Result<(), ErrorMemoria> {
+        // Hay memoria libre suficiente pero repartida en huecos: compactar antes
+        if self.contigua.mayor_hueco() < proceso.tamaño_kb
+            && self.contigua.libre_total() >= proceso.tamaño_kb
+        {
+            self.registrar_log(&format!(
+                "[COMPACTAR] Automática: P{} necesita {}KB y el mayor hueco es de {}KB",
+                proceso.pid,
+                proceso.tamaño_kb,
+                self.contigua.mayor_hueco()
+            ));
+            self.compactar_memoria();
+        }
+        let inicio = self.contigua.asignar(proceso.pid, proceso.tamaño_kb)?;
+        self.procesos_creados += 1;
+        self.registrar_log(&format!(
+            "[NUEVO] Proceso P{} '{}' creado ({}KB en [{}, {}) KB, {})",
+            proceso.pid,
+            proceso.nombre,
+            proceso.tamaño_kb,
+            inicio,
+            inicio + proceso.tamaño_kb,
+            self.contigua.estrategia.nombre()
+        ));
+        self.procesos.push(proceso);
+        Ok(())
+    }
+
+    /// Admisión en modo buddy: el proceso recibe el menor bloque de potencia
+    /// de dos que cubre `tamaño_kb`, partiendo bloques mayores si hace falta
+    fn asignar_buddy(&mut self, proceso: Proceso) -> Result<(), ErrorMemoria> {
+        let inicio = self.buddy.asignar(proceso.pid, proceso.tamaño_kb)?;
+        let bloque = self
+            .buddy
+            .bloque_de(proceso.pid)
+            .map_or(0, |(_, bloque)| bloque.tamaño());
+        self.procesos_creados += 1;
+        self.registrar_log(&format!(
+            "[NUEVO] Proceso P{} '{}' creado ({}KB en bloque buddy de {}KB en [{}, {}) KB, {}KB de fragmentación interna)",
+            proceso.pid,
+            proceso.nombre,
+            proceso.tamaño_kb,
+            bloque,
+            inicio,
+            inicio + bloque,
+            bloque - proceso.tamaño_kb
+        ));
+        self.procesos.push(proceso);
+        Ok(())
+    }
+
+    /// Compacta la memoria contigua: junta todos los procesos al principio y
+    /// deja un solo hueco. Registra en el log los KB copiados y los bloques
+    /// reubicados. Devuelve los KB movidos (0 en modo paginación).
+    pub fn compactar_memoria(&mut self) -> usize {
+        if self.modo != ModoMemoria::Contigua {
+            self.registrar_log("[COMPACTAR] Solo disponible en modo de memoria contigua");
+            return 0;
+        }
+        let huecos_previos = self.contigua.huecos().count();
+        let (movidos, reubicados) = self.contigua.compactar();
+        self.registrar_log(&format!(
+            "[COMPACTAR] {}KB movidos en {} bloques ({} huecos -> 1, total acumulado {}KB)",
+            movidos, reubicados, huecos_previos, self.contigua.kb_movidos
+        ));
+        movidos
+    }
+
+    /// Reserva un objeto de la caché slab indicada. Si todos sus slabs están
+    /// llenos, la caché crece con un marco libre o, con la RAM llena, con uno
+    /// desalojado a Swap como cualquier reemplazo global. Devuelve el marco y
+    /// el índice del objeto dentro del slab.
+    pub fn reservar_objeto(&mut self, cache: &str) -> Result<(usize, usize), ErrorMemoria> {
+        if self.modo != ModoMemoria::Paginacion {
+            return Err(ErrorMemoria::ModoIncompatible(self.modo.nombre()));
+        }
+        let idx = self
+            .slab
+            .indice(cache)
+            .ok_or_else(|| ErrorMemoria::CacheDesconocida(cache.to_string()))?;
+        if self.slab.caches[idx].objetos_por_slab == 0 {
+            return Err(ErrorMemoria::ObjetoDemasiadoGrande {
+                cache: cache.to_string(),
+                tamaño: self.slab.caches[idx].tamaño_objeto,
+                marco: self.tamaño_pagina_kb * 1024,
+            });
+        }
+        if let Some(objeto) = self.slab.caches[idx].reservar() {
+            return Ok(objeto);
+        }
+
+        let marco = self.marco_para_nucleo()?;
+        self.marcos_ram[marco].nucleo = true;
+        let cache = &mut self.slab.caches[idx];
+        cache.crecer(marco);
+        let (nombre, objetos, tamaño) = (
+            cache.nombre.clone(),
+            cache.objetos_por_slab,
+            cache.tamaño_objeto,
+        );
+        let objeto = cache.reservar().unwrap_or((marco, 0));
+        self.registrar_log(&format!(
+            "[SLAB] Caché {} crece: marco {} partido en {} objetos de {} B",
+            nombre, marco, objetos, tamaño
+        ));
+        Ok(objeto)
+    }
+
+    /// Marco para un slab nuevo: uno libre o, si no hay, la víctima de un
+    /// reemplazo global (el núcleo no tiene cuota ni páginas propias)
+    fn marco_para_nucleo(&mut self) -> Result<usize, ErrorMemoria> {
+        if self.marcos_ram.is_empty() {
+            return Err(ErrorMemoria::SinMarcos);
+        }
+        if let Some(libre) = self.marcos_ram.iter().position(Marco::libre) {
+            return Ok(libre);
+        }
+        let idx = self.reemplazar_pagina(None, None)?;
+        let marco = &mut self.marcos_ram[idx];
+        marco.proceso_id = None;
+        marco.pagina_id = None;
+        Ok(idx)
+    }
+
+    /// Devuelve un objeto a su caché (el slab sigue reservado aunque quede
+    /// vacío). Devuelve si el objeto estaba en uso.
+    pub fn liberar_objeto(
+        &mut self,
+        cache: &str,
+        marco: usize,
+        indice: usize,
+    ) -> Result<bool, ErrorMemoria> {
+        let idx = self
+            .slab
+            .indice(cache)
+            .ok_or_else(|| ErrorMemoria::CacheDesconocida(cache.to_string()))?;
+        Ok(self.slab.caches[idx].liberar(marco, indice))
+    }
+
+    /// Devuelve a la RAM los marcos de hasta `maximo` slabs vacíos
+    pub fn reducir_slabs(&mut self, maximo: usize) -> Vec<usize> {
+        let mut marcos = Vec::new();
+        for cache in &mut self.slab.caches {
+            let restantes = maximo - marcos.len();
+            marcos.extend(cache.reducir(restantes));
+        }
+        for &marco in &marcos {
+            self.marcos_ram[marco].nucleo = false;
+        }
+        if !marcos.is_empty() {
+            self.registrar_log(&format!(
+                "[SLAB] {} slabs vacíos devueltos a la RAM (marcos {:?})",
+                marcos.len(),
+                marcos
+            ));
+        }
+        marcos
+    }
+
+    /// Actividad del núcleo: reserva `cantidad` objetos de una caché al azar
+    pub fn reservar_objetos_aleatorios(&mut self, cantidad: usize) {
+        let mut rng = rand::thread_rng();
+        let Some(nombre) = self.slab.caches.choose(&mut rng).map(|c| c.nombre.clone()) else {
+            return;
+        };
+        for reservados in 0..cantidad {
+            if let Err(error) = self.reservar_objeto(&nombre) {
+                self.registrar_log(&format!(
+                    "[SLAB] {} objetos de {} reservados: {}",
+                    reservados, nombre, error
+                ));
+                return;
+            }
+        }
+        self.registrar_log(&format!(
+            "[SLAB] {} objetos de {} reservados",
+            cantidad, nombre
+        ));
+    }
+
+    /// Actividad del núcleo: libera `cantidad` objetos en uso al azar
+    pub fn liberar_objetos_aleatorios(&mut self, cantidad: usize) {
+        let mut rng = rand::thread_rng();
+        let objetos = self.slab.objetos();
+        let elegidos: Vec<_> = objetos
+            .choose_multiple(&mut rng, cantidad)
+            .copied()
+            .collect();
+        for &(cache, marco, indice) in &elegidos {
+            self.slab.caches[cache].liberar(marco, indice);
+        }
+        self.registrar_log(&format!(
+            "[SLAB] {} objetos del núcleo liberados",
+            elegidos.len()
+        ));
+    }
+
+    /// Mata un proceso aleatorio y libera sus recursos (Requisito B)
+    pub fn matar_proceso_aleatorio(&mut self) {
+        let mut rng = rand::thread_rng();
+        if let Some(pid_victima) = self.procesos.choose(&mut rng).map(|p| p.pid) {
+            let _ = self.matar_proceso(pid_victima);
+        }
+    }
+
+    /// Termina el proceso indicado y libera sus marcos y su espacio en Swap.
+    /// Falla con `PidDesconocido` si no existe ningún proceso con ese PID.
+    pub fn matar_proceso(&mut self, pid_victima: usize) -> Result<(), ErrorMemoria> {
+        if self.proceso(pid_victima).is_none() {
+            return Err(ErrorMemoria::PidDesconocido(pid_victima));
+        }
+
+        // Liberar marcos RAM ocupados por el proceso
+        for marco in &mut self.marcos_ram {
+            if marco.proceso_id == Some(pid_victima) {
+                self.politica.al_liberar(marco);
+                self.tabla_invertida.quitar(marco.id);
+                marco.proceso_id = None;
+                marco.pagina_id = None;
+            }
+        }
+
+        self.tlb.invalidar_proceso(pid_victima);
+        self.contigua.liberar(pid_victima);
+        self.buddy.liberar(pid_victima);
+        self.tabla_hash.quitar_proceso(pid_victima);
+
+        // Limpiar páginas del proceso en Swap y sus referencias pendientes
+        self.cola_swap.retain(|&(pid, _)| pid != pid_victima);
+        self.referencias_pendientes
+            .retain(|&(pid, _, _)| pid != pid_victima);
+
+        // Eliminar proceso de la lista
+        self.procesos.retain(|p| p.pid != pid_victima);
+        self.procesos_finalizados += 1;
+        if self.pff.is_none() {
+            self.repartir_cuotas();
+        }
+
+        self.registrar_log(&format!(
+            "[TERM] Proceso P{} terminado y liberado",
+            pid_victima
+        ));
+        Ok(())
+    }
+
+    /// Accede a una página de un proceso: si está en RAM actualiza sus bits
+    /// de uso; si no, la carga (con reemplazo si la RAM está llena).
+    /// Las escrituras además activan el bit M de la página.
+    pub fn acceder_pagina(
+        &mut self,
+        pid: usize,
+        idx_pagina: usize,
+        tipo: TipoAcceso,
+    ) -> Result<(), ErrorMemoria> {
+        let en_tabla = match self.proceso(pid) {
+            None => return Err(ErrorMemoria::PidDesconocido(pid)),
+            Some(proceso) => match proceso.paginas.iter().find(|p| p.id == idx_pagina) {
+                None => {
+                    return Err(ErrorMemoria::PaginaFueraDeRango {
+                        pid,
+                        pagina: idx_pagina,
+                    })
+                }
+                Some(pagina) => pagina.marco_id,
+            },
+        };
+
+        // La TLB se consulta antes que la tabla de páginas
+        let en_tlb = self.tlb.buscar(pid, idx_pagina);
+        let marco_actual = match en_tlb {
+            Some(marco) => Some(marco),
+            None => self.recorrer_tablas(pid, idx_pagina, en_tabla),
+        };
+
+        self.medir_pff(pid, marco_actual.is_none());
+
+        if self.historial_referencias.len() == VENTANA_OPTIMO {
+            self.historial_referencias.pop_front();
+        }
+        self.historial_referencias
+            .push_back((pid, idx_pagina, marco_actual.is_none()));
+        self.referencias_registradas += 1;
+
+        if marco_actual.is_none() {
+            // Fallo de página: la página no está en RAM
+            self.fallos_pagina += 1;
+            self.traer_pagina(pid, idx_pagina)?;
+            let marco_nuevo = self
+                .proceso(pid)
+                .and_then(|p| p.paginas.iter().find(|pg| pg.id == idx_pagina))
+                .and_then(|pg| pg.marco_id);
+            if let Some(marco) = marco_nuevo {
+                self.tlb.insertar(pid, idx_pagina, marco);
+            }
+        } else {
+            if let (None, Some(marco)) = (en_tlb, marco_actual) {
+                self.tlb.insertar(pid, idx_pagina, marco);
+            }
+            self.accesos_totales += 1;
+            let tiempo_acceso = self.accesos_totales as u64;
+            if let Some(pagina) = self.obtener_info_pagina(pid, idx_pagina) {
+                pagina.referenciada = true;
+                pagina.ultimo_uso = tiempo_acceso;
+                pagina.contador_accesos += 1;
+            }
+            self.envejecer_contadores();
+            if let Some(idx_marco) = marco_actual {
+                self.politica.al_acceder(&self.marcos_ram[idx_marco]);
+            }
+        }
+
+        // Tiempo virtual del proceso: avanza una unidad por cada referencia propia
+        if let Some(proceso) = self.procesos.iter_mut().find(|p| p.pid == pid) {
+            if marco_actual.is_none() {
+                proceso.fallos_pagina += 1;
+            }
+            proceso.tiempo_virtual += 1;
+            let tiempo_virtual = proceso.tiempo_virtual;
+            if let Some(pagina) = proceso.paginas.iter_mut().find(|p| p.id == idx_pagina) {
+                pagina.ultimo_uso_virtual = Some(tiempo_virtual);
+                if tipo == TipoAcceso::Escritura {
+                    pagina.modificada = true;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decaimiento de los contadores LFU/MFU: cada `parametros.intervalo_decaimiento`
+    /// accesos todos los contadores se dividen a la mitad
+    fn envejecer_contadores(&mut self) {
+        let intervalo = self.parametros.intervalo_decaimiento;
+        if intervalo == 0 || !self.accesos_totales.is_multiple_of(intervalo) {
+            return;
+        }
+        for pagina in self.procesos.iter_mut().flat_map(|p| p.paginas.iter_mut()) {
+            pagina.contador_accesos >>= 1;
+        }
+    }
+
+    /// Avanza un tick de simulación: cada proceso activo genera
+    /// `referencias_por_proceso` accesos a memoria según su flujo de referencias
+    pub fn ejecutar_tick(&mut self, referencias_por_proceso: usize) {
+        // Interrupción de reloj: la política puede limpiar bits R, envejecer...
+        let mut vista = VistaMemoria {
+            marcos: &self.marcos_ram,
+            procesos: &mut self.procesos,
+            tiempo: self.accesos_totales as u64,
+            futuro: &self.referencias_pendientes,
+            entrante: None,
+            restringido_a: None,
+        };
+        self.politica.al_tick(&mut vista);
+
+        let mut rng = rand::thread_rng();
+        for proceso in &mut self.procesos {
+            let total = proceso.paginas.len();
+            for _ in 0..referencias_por_proceso {
+                if let Some((pagina, tipo)) = proceso.referencias.siguiente(total, &mut rng) {
+                    self.referencias_pendientes
+                        .push_back((proceso.pid, pagina, tipo));
+                }
+            }
+        }
+        self.ejecutar_pendientes();
+    }
+
+    /// Ejecuta una cadena de lecturas `(PID, página)` conocida de antemano
+    /// (el algoritmo Óptimo la ve completa). Devuelve los fallos producidos.
+    pub fn ejecutar_cadena(&mut self, cadena: &[(usize, usize)]) -> usize {
+        let fallos_previos = self.fallos_pagina;
+        self.referencias_pendientes.extend(
+            cadena
+                .iter()
+                .map(|&(pid, pagina)| (pid, pagina, TipoAcceso::Lectura)),
+        );
+        self.ejecutar_pendientes();
+        self.fallos_pagina - fallos_previos
+    }
+
+    /// Consume las referencias pendientes en orden
+    fn ejecutar_pendientes(&mut self) {
+        while let Some((pid, pagina, tipo)) = self.referencias_pendientes.pop_front() {
+            if let Err(error) = self.acceder_pagina(pid, pagina, tipo) {
+                self.registrar_log(&format!("[ERROR] {}", error));
+            }
+        }
+    }
+
+    /// Atiende un fallo de página (paginación bajo demanda): si la página está
+    /// en Swap la saca del área de intercambio antes de cargarla en un marco
+    fn traer_pagina(&mut self, pid: usize, idx_pagina: usize) -> Result<(), ErrorMemoria> {
+        let pos_swap = self
+            .cola_swap
+            .iter()
+            .position(|&entrada| entrada == (pid, idx_pagina));
+
+        // Liberar su hueco en Swap primero: la víctima puede necesitarlo
+        if let Some(pos) = pos_swap {
+            self.cola_swap.remove(pos);
+        }
+
+        if let Err(error) = self.cargar_pagina(pid, idx_pagina) {
+            if let Some(pos) = pos_swap {
+                self.cola_swap.insert(pos, (pid, idx_pagina));
+            }
+            return Err(error);
+        }
+
+        if pos_swap.is_some() {
+            self.swaps_entrada += 1;
+            let marco = self
+                .proceso(pid)
+                .and_then(|p| p.paginas.iter().find(|pg| pg.id == idx_pagina))
+                .and_then(|pg| pg.marco_id);
+            self.registrar_log(&format!(
+                "[SWAP] Proceso P{} Página {} traída desde Swap al Marco {}",
+                pid,
+                idx_pagina,
+                marco.map_or("?".to_string(), |m| m.to_string())
+            ));
+        }
+        Ok(())
+    }
+
+    /// Carga una página en memoria RAM (con swapping si es necesario)
+    fn cargar_pagina(&mut self, pid: usize, idx_pagina: usize) -> Result<(), ErrorMemoria> {
+        self.accesos_totales += 1;
+        let tiempo_acceso = self.accesos_totales as u64; // Capturar antes del préstamo mutable
+
+        let idx_objetivo = self.obtener_marco(pid, idx_pagina)?;
+
+        // Asignar página al marco
+        self.marcos_ram[idx_objetivo].proceso_id = Some(pid);
+        self.marcos_ram[idx_objetivo].pagina_id = Some(idx_pagina);
+        self.tabla_invertida.insertar(idx_objetivo, pid, idx_pagina);
+        self.tabla_hash
+            .asignar_marco(pid, idx_pagina, Some(idx_objetivo));
+
+        // Actualizar tabla de páginas del proceso
+        if let Some(pagina) = self.obtener_info_pagina(pid, idx_pagina) {
+            pagina.marco_id = Some(idx_objetivo);
+            pagina.referenciada = true;
+            pagina.ultimo_uso = tiempo_acceso; // Usar valor capturado
+            pagina.contador_accesos = 1;
+            pagina.modificada = false;
+            pagina.edad = 0;
+        }
+        self.envejecer_contadores();
+
+        self.politica.al_cargar(&self.marcos_ram[idx_objetivo]);
+
+        Ok(())
+    }
+
+    /// Decide de dónde sale el marco para una página entrante según el
+    /// alcance: con reemplazo local (o con la cuota agotada) la víctima es una
+    /// página del propio proceso; con cuotas y la RAM llena, se le quita el
+    /// marco al proceso que más excede la suya
+    fn obtener_marco(&mut self, pid: usize, idx_pagina: usize) -> Result<usize, ErrorMemoria> {
+        if self.marcos_ram.is_empty() {
+            return Err(ErrorMemoria::SinMarcos);
+        }
+        let propios = self.marcos_de(pid);
+        let libres = self.marcos_disponibles();
+        // Solo un proceso registrado tiene tabla de páginas donde elegir víctima
+        let local = propios > 0
+            && self.proceso(pid).is_some_and(|p| match self.alcance {
+                AlcanceReemplazo::Global => false,
+                AlcanceReemplazo::Local => libres == 0,
+                AlcanceReemplazo::Cuota => p.cuota_marcos.is_some_and(|cuota| propios >= cuota),
+            });
+        if local {
+            return self.reemplazar_pagina(Some((pid, idx_pagina)), Some(pid));
+        }
+        if self.alcance == AlcanceReemplazo::Local && libres == 0 && propios == 0 {
+            self.registrar_log(&format!(
+                "[ALCANCE] P{} no tiene marcos propios: la página {} se carga con reemplazo global",
+                pid, idx_pagina
+            ));
+        }
+
+        // Buscar marco libre en RAM
+        if let Some(libre) = self.marcos_ram.iter().position(Marco::libre) {
+            return Ok(libre);
+        }
+        // Antes de desalojar, el núcleo devuelve un slab vacío si lo tiene
+        if let Some(marco) = self.reducir_slabs(1).first() {
+            return Ok(*marco);
+        }
+
+        // RAM llena, activar algoritmo de reemplazo (Requisito E)
+        let excedido = self
+            .procesos
+            .iter()
+            .filter_map(|p| {
+                let exceso = self.marcos_de(p.pid).checked_sub(p.cuota_marcos?)?;
+                (exceso > 0).then_some((exceso, p.pid))
+            })
+            .max()
+            .map(|(_, pid)| pid);
+        self.reemplazar_pagina(Some((pid, idx_pagina)), excedido)
+    }
+
+    /// Selecciona y reemplaza una página víctima usando la política activa (Requisito E)
+    fn reemplazar_pagina(
+        &mut self,
+        entrante: Option<(usize, usize)>,
+        restringido_a: Option<usize>,
+    ) -> Result<usize, ErrorMemoria> {
+        // Verificar capacidad del Swap antes de tocar ninguna página
+        if self.cola_swap.len() >= self.capacidad_swap {
+            return Err(ErrorMemoria::SwapLleno {
+                capacidad: self.capacidad_swap,
+            });
+        }
+
+        let mut vista = VistaMemoria {
+            marcos: &self.marcos_ram,
+            procesos: &mut self.procesos,
+            tiempo: self.accesos_totales as u64,
+            futuro: &self.referencias_pendientes,
+            entrante,
+            restringido_a,
+        };
+        let idx_victima = self
+            .politica
+            .elegir_victima(&mut vista)
+            .filter(|&idx| vista.es_candidato(idx))
+            .ok_or_else(|| ErrorMemoria::VictimaInvalida {
+                politica: self.politica.nombre().to_string(),
+            })?;
+
+        // Obtener información de la página víctima
+        let (pid_victima, pag_victima) = {
+            let marco = &self.marcos_ram[idx_victima];
+            (marco.proceso_id.unwrap(), marco.pagina_id.unwrap())
+        };
+
+        // Actualizar tabla de páginas (marcar como no presente en RAM)
+        let mut sucia = false;
+        if let Some(pagina) = self.obtener_info_pagina(pid_victima, pag_victima) {
+            pagina.marco_id = None;
+            sucia = pagina.modificada;
+            pagina.modificada = false;
+        }
+
+        // Mover página a Swap (las modificadas requieren escritura en disco)
+        self.tlb.invalidar(pid_victima, pag_victima);
+        self.tabla_invertida.quitar(idx_victima);
+        self.tabla_hash
+            .asignar_marco(pid_victima, pag_victima, None);
+        self.politica.al_desalojar(&self.marcos_ram[idx_victima]);
+        self.cola_swap.push_back((pid_victima, pag_victima));
+        self.swaps_salida += 1;
+        match entrante {
+            Some((pid, _)) if pid != pid_victima => self.robos_marcos += 1,
+            Some(_) => {}
+            None => self.robos_nucleo += 1,
+        }
+        if sucia {
+            self.escrituras_swap += 1;
+        }
+
+        self.registrar_log(&format!(
+            "[SWAP] Proceso P{} Página {} movida a Swap (Marco {} liberado{})",
+            pid_victima,
+            pag_victima,
+            idx_victima,
+            if sucia { ", escrita a disco" } else { "" }
+        ));
 
         Ok(idx_victima)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 16 marcos de 4 KB y sin Swap: cualquier desalojo falla
+    fn gestor_sin_swap() -> GestorMemoria {
+        GestorMemoria::new(64, 0, 4, AlgoritmoReemplazo::FIFO)
+    }
+
+    #[test]
+    fn admision_cuenta_los_slabs_vacios_como_libres() {
+        let mut gestor = gestor_sin_swap();
+        let (marco_slab, objeto) = gestor.reservar_objeto("mm_struct").unwrap();
+        assert_eq!(
+            gestor.liberar_objeto("mm_struct", marco_slab, objeto),
+            Ok(true)
+        );
+        gestor
+            .asignar_proceso(Proceso::new(1, "a", 60, (0, 0, 0)))
+            .unwrap();
+        assert_eq!(gestor.contar_marcos_libres(), 0);
+
+        // La RAM está llena salvo el slab vacío: el núcleo lo devuelve
+        // y el proceso entra sin desalojar nada
+        gestor
+            .asignar_proceso(Proceso::new(2, "b", 4, (0, 0, 0)))
+            .unwrap();
+        assert_eq!(gestor.marcos_ram[marco_slab].proceso_id, Some(2));
+        assert_eq!((gestor.swaps_salida, gestor.fallos_pagina), (0, 0));
+        assert!(gestor.verificar_consistencia().is_empty());
+    }
+
+    #[test]
+    fn swap_lleno_en_la_carga_deshace_la_admision() {
+        // Un único hueco de Swap: el primer desalojo cabe y el segundo no
+        let mut gestor = GestorMemoria::new(64, 4, 4, AlgoritmoReemplazo::FIFO);
+        gestor
+            .asignar_proceso(Proceso::new(1, "a", 64, (0, 0, 0)))
+            .unwrap();
+        let marcos_previos = gestor.marcos_ram.clone();
+        let swap_previo = gestor.cola_swap.clone();
+        let paginas_previas: Vec<(usize, Vec<Pagina>)> = gestor
+            .procesos
+            .iter()
+            .map(|p| (p.pid, p.paginas.clone()))
+            .collect();
+        let contadores_previos = (
+            gestor.fallos_pagina,
+            gestor.accesos_totales,
+            gestor.swaps_salida,
+            gestor.escrituras_swap,
+            gestor.robos_marcos,
+        );
+        let logs_previos = gestor.logs.clone();
+
+        // Saltarse la comprobación previa para forzar el fallo a mitad de carga
+        let mut proceso = Proceso::new(2, "b", 12, (0, 0, 0));
+        proceso.paginas = (0..3)
+            .map(|id| Pagina {
+                id,
+                marco_id: None,
+                referenciada: true,
+                ultimo_uso: 0,
+                contador_accesos: 0,
+                modificada: false,
+                edad: 0,
+                ultimo_uso_virtual: None,
+            })
+            .collect();
+        assert_eq!(
+            gestor.cargar_proceso(proceso, 3),
+            Err(ErrorMemoria::SwapLleno { capacidad: 1 })
+        );
+
+        assert_eq!(gestor.marcos_ram, marcos_previos);
+        assert_eq!(gestor.cola_swap, swap_previo);
+        let paginas: Vec<(usize, Vec<Pagina>)> = gestor
+            .procesos
+            .iter()
+            .map(|p| (p.pid, p.paginas.clone()))
+            .collect();
+        assert_eq!(paginas, paginas_previas);
+        assert_eq!(
+            (
+                gestor.fallos_pagina,
+                gestor.accesos_totales,
+                gestor.swaps_salida,
+                gestor.escrituras_swap,
+                gestor.robos_marcos,
+            ),
+            contadores_previos
+        );
+        assert_eq!(gestor.logs, logs_previos);
+        assert!(gestor.verificar_consistencia().is_empty());
+    }
+
+    #[test]
+    fn traduccion_con_paginacion() {
+        let mut gestor = gestor_sin_swap();
+        // 6 KB: la segunda página solo es válida hasta el byte 6143
+        gestor
+            .asignar_proceso(Proceso::new(1, "a", 6, (0, 0, 0)))
+            .unwrap();
+        let marco = gestor.procesos[0].paginas[1].marco_id.unwrap();
+
+        let traduccion = gestor.traducir_direccion(1, 4096 + 0x10).unwrap();
+        assert_eq!((traduccion.pagina, traduccion.desplazamiento), (1, 0x10));
+        assert_eq!(traduccion.marco, marco);
+        assert_eq!(traduccion.direccion_fisica, marco * 4096 + 0x10);
+        assert!(!traduccion.fallo);
+
+        assert_eq!(
+            gestor.traducir_direccion(1, 6 * 1024),
+            Err(ErrorMemoria::FueraDeLimite {
+                pid: 1,
+                direccion: 6 * 1024,
+                limite: 6 * 1024,
+            })
+        );
+    }
+
+    #[test]
+    fn traduccion_con_base_y_limite() {
+        for modo in [ModoMemoria::Contigua, ModoMemoria::Buddy] {
+            // 1 MB para que quepan dos bloques buddy del mínimo de 64 KB
+            let mut gestor = GestorMemoria::new(1024, 0, 4, AlgoritmoReemplazo::FIFO);
+            gestor.modo = modo;
+            gestor
+                .asignar_proceso(Proceso::new(1, "a", 20, (0, 0, 0)))
+                .unwrap();
+            gestor
+                .asignar_proceso(Proceso::new(2, "b", 10, (0, 0, 0)))
+                .unwrap();
+            // Primer ajuste pone P2 justo detrás de P1; buddy, tras su bloque de 64 KB
+            let base = match modo {
+                ModoMemoria::Buddy => 64 * 1024,
+                _ => 20 * 1024,
+            };
+
+            let traduccion = gestor.traducir_direccion(2, 0x123).unwrap();
+            assert_eq!(traduccion.direccion_fisica, base + 0x123);
+
+            // En buddy el byte siguiente cae en el relleno del bloque de 64 KB
+            assert_eq!(
+                gestor.traducir_direccion(2, 10 * 1024),
+                Err(ErrorMemoria::FueraDeLimite {
+                    pid: 2,
+                    direccion: 10 * 1024,
+                    limite: 10 * 1024,
+                })
+            );
+        }
+    }
+}
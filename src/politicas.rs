@@ -0,0 +1,1168 @@
+use crate::models::{Marco, Pagina, Proceso, TipoAcceso};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+/// Identidad de una página lógica: (PID, página)
+type Clave = (usize, usize);
+
+/// Clave de la página cargada en un marco
+fn clave_de(marco: &Marco) -> Option<Clave> {
+    Some((marco.proceso_id?, marco.pagina_id?))
+}
+
+/// Quita una clave de una lista LRU/FIFO; devuelve si estaba
+fn quitar(lista: &mut VecDeque<Clave>, clave: Clave) -> bool {
+    match lista.iter().position(|&c| c == clave) {
+        Some(pos) => {
+            lista.remove(pos);
+            true
+        }
+        None => false,
+    }
+}
+
+// --- INTERFAZ DE POLÍTICAS DE REEMPLAZO ---
+
+/// Vista del estado de memoria que el gestor presta a la política al elegir víctima
+pub struct VistaMemoria<'a> {
+    pub marcos: &'a [Marco],         // Memoria física (solo lectura)
+    pub procesos: &'a mut [Proceso], // Tablas de páginas (bits R, último uso...)
+    pub tiempo: u64,                 // Tiempo virtual actual (accesos totales)
+    pub futuro: &'a VecDeque<(usize, usize, TipoAcceso)>, // Referencias futuras conocidas
+    pub entrante: Option<(usize, usize)>, // Página (PID, página) que provoca el reemplazo
+    pub restringido_a: Option<usize>, // Reemplazo local: solo marcos de este PID son víctimas
+}
+
+impl VistaMemoria<'_> {
+    /// Página lógica cargada en el marco indicado
+    pub fn pagina(&self, idx_marco: usize) -> Option<&Pagina> {
+        let marco = self.marcos.get(idx_marco)?;
+        let (pid, pg) = (marco.proceso_id?, marco.pagina_id?);
+        self.procesos
+            .iter()
+            .find(|p| p.pid == pid)
+            .and_then(|p| p.paginas.iter().find(|page| page.id == pg))
+    }
+
+    /// Página lógica cargada en el marco indicado (mutable)
+    pub fn pagina_mut(&mut self, idx_marco: usize) -> Option<&mut Pagina> {
+        let marco = self.marcos.get(idx_marco)?;
+        let (pid, pg) = (marco.proceso_id?, marco.pagina_id?);
+        self.procesos
+            .iter_mut()
+            .find(|p| p.pid == pid)
+            .and_then(|p| p.paginas.iter_mut().find(|page| page.id == pg))
+    }
+
+    /// Proceso dueño de la página cargada en el marco indicado
+    pub fn proceso(&self, idx_marco: usize) -> Option<&Proceso> {
+        let pid = self.marcos.get(idx_marco)?.proceso_id?;
+        self.procesos.iter().find(|p| p.pid == pid)
+    }
+
+    /// El marco está ocupado y puede ser víctima (respeta `restringido_a`)
+    pub fn es_candidato(&self, idx_marco: usize) -> bool {
+        self.marcos.get(idx_marco).is_some_and(|marco| {
+            marco.proceso_id.is_some()
+                && self
+                    .restringido_a
+                    .is_none_or(|pid| marco.proceso_id == Some(pid))
+        })
+    }
+
+    /// Índices de los marcos que pueden ser víctima
+    pub fn ocupados(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.marcos.len()).filter(|&idx| self.es_candidato(idx))
+    }
+}
+
+/// Política de reemplazo de páginas enchufable.
+///
+/// El gestor avisa a la política de cada evento sobre un marco (carga, acceso,
+/// desalojo hacia Swap y liberación por fin de proceso) y le pide una víctima
+/// cuando la RAM está llena o el proceso agotó su cuota de marcos; en ese caso
+/// la víctima debe cumplir [`VistaMemoria::es_candidato`].
+///
+/// Los ganchos reciben el marco con su contenido vigente: en `al_cargar` ya
+/// contiene la página nueva, en `al_desalojar` y `al_liberar` todavía
+/// contiene la página saliente.
+pub trait PoliticaReemplazo {
+    /// Nombre visible de la política (interfaz y registro)
+    fn nombre(&self) -> &str;
+
+    /// Una página acaba de cargarse en el marco
+    fn al_cargar(&mut self, _marco: &Marco) {}
+
+    /// Acierto: se accedió a una página ya presente en el marco
+    fn al_acceder(&mut self, _marco: &Marco) {}
+
+    /// La página del marco va a ser enviada a Swap
+    fn al_desalojar(&mut self, _marco: &Marco) {}
+
+    /// El marco queda libre porque su proceso terminó
+    fn al_liberar(&mut self, _marco: &Marco) {}
+
+    /// Interrupción periódica de reloj (un tick de simulación)
+    fn al_tick(&mut self, _vista: &mut VistaMemoria) {}
+
+    /// Elige el marco víctima entre los candidatos (None si no puede elegir)
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize>;
+
+    /// Copia del estado interno, para que el gestor pueda deshacer una
+    /// admisión fallida (None = no sabe copiarse y el gestor la reconstruye)
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        None
+    }
+
+    /// Líneas de estado interno para mostrar en la interfaz (listas, parámetros...)
+    fn estado(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Manecillas de reloj de la política: (etiqueta, marco al que apuntan)
+    fn manecillas(&self) -> Vec<(&'static str, usize)> {
+        Vec::new()
+    }
+}
+
+/// Constructor de políticas usado por el registro del gestor
+pub type FabricaPolitica = Box<dyn Fn() -> Box<dyn PoliticaReemplazo>>;
+
+// --- POLÍTICAS INCLUIDAS ---
+
+/// FIFO: reemplaza la página que lleva más tiempo cargada
+#[derive(Clone, Default)]
+pub struct Fifo {
+    cola: VecDeque<usize>, // Marcos en orden de carga
+}
+
+impl PoliticaReemplazo for Fifo {
+    fn nombre(&self) -> &str {
+        "FIFO"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn al_cargar(&mut self, marco: &Marco) {
+        self.cola.push_back(marco.id);
+    }
+
+    fn al_desalojar(&mut self, marco: &Marco) {
+        self.cola.retain(|&id| id != marco.id);
+    }
+
+    fn al_liberar(&mut self, marco: &Marco) {
+        self.cola.retain(|&id| id != marco.id);
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        self.cola
+            .iter()
+            .copied()
+            .find(|&idx| vista.es_candidato(idx))
+    }
+}
+
+/// Algoritmo del Reloj: segunda oportunidad según el bit de referencia
+#[derive(Clone, Default)]
+pub struct Reloj {
+    puntero: usize, // Posición actual de la manecilla
+}
+
+impl PoliticaReemplazo for Reloj {
+    fn nombre(&self) -> &str {
+        "Reloj"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn manecillas(&self) -> Vec<(&'static str, usize)> {
+        vec![("R", self.puntero)]
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        let len = vista.marcos.len();
+        // Dos vueltas bastan: la primera limpia todos los bits R
+        for _ in 0..len * 2 {
+            let idx = self.puntero % len;
+            self.puntero = (idx + 1) % len;
+            if !vista.es_candidato(idx) {
+                continue;
+            }
+
+            if let Some(pagina) = vista.pagina_mut(idx) {
+                if pagina.referenciada {
+                    // Dar segunda oportunidad
+                    pagina.referenciada = false;
+                } else {
+                    // No ha sido referenciada, es víctima
+                    return Some(idx);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// LRU: reemplaza la página menos recientemente usada
+#[derive(Clone, Default)]
+pub struct Lru;
+
+impl PoliticaReemplazo for Lru {
+    fn nombre(&self) -> &str {
+        "LRU"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        vista
+            .ocupados()
+            .filter_map(|idx| vista.pagina(idx).map(|p| (idx, p.ultimo_uso)))
+            .min_by_key(|&(_, uso)| uso)
+            .map(|(idx, _)| idx)
+    }
+}
+
+/// Óptimo (Belady MIN): reemplaza la página cuyo próximo uso está más lejos en
+/// la cadena de referencias futuras conocida. Las páginas que no vuelven a
+/// aparecer son víctimas inmediatas; sin futuro conocido se comporta como LRU.
+#[derive(Clone, Default)]
+pub struct Optimo;
+
+impl PoliticaReemplazo for Optimo {
+    fn nombre(&self) -> &str {
+        "Optimo"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        // Posición de la primera referencia futura a cada página
+        let mut proximo_uso: HashMap<(usize, usize), usize> = HashMap::new();
+        for (pos, &(pid, pagina, _)) in vista.futuro.iter().enumerate() {
+            proximo_uso.entry((pid, pagina)).or_insert(pos);
+        }
+
+        vista
+            .ocupados()
+            .filter_map(|idx| {
+                let marco = &vista.marcos[idx];
+                let clave = (marco.proceso_id?, marco.pagina_id?);
+                let distancia = proximo_uso.get(&clave).copied().unwrap_or(usize::MAX);
+                let uso = vista.pagina(idx).map_or(0, |p| p.ultimo_uso);
+                // Más lejana primero; a igualdad, la menos recientemente usada
+                Some((idx, distancia, std::cmp::Reverse(uso)))
+            })
+            .max_by_key(|&(_, distancia, uso)| (distancia, uso))
+            .map(|(idx, _, _)| idx)
+    }
+}
+
+/// Número de fallos que produce el algoritmo óptimo (Belady MIN) sobre una
+/// cadena de referencias `(PID, página)` con `num_marcos` marcos inicialmente
+/// vacíos. Es la cota inferior con la que comparar cualquier otra política.
+pub fn fallos_optimos(cadena: &[(usize, usize)], num_marcos: usize) -> usize {
+    // Próxima aparición de la misma página para cada posición de la cadena
+    let mut siguiente = vec![usize::MAX; cadena.len()];
+    let mut ultima_vista: HashMap<(usize, usize), usize> = HashMap::new();
+    for (pos, referencia) in cadena.iter().enumerate().rev() {
+        if let Some(&pos_siguiente) = ultima_vista.get(referencia) {
+            siguiente[pos] = pos_siguiente;
+        }
+        ultima_vista.insert(*referencia, pos);
+    }
+
+    let mut residentes: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut por_distancia: BTreeSet<(usize, (usize, usize))> = BTreeSet::new();
+    let mut fallos = 0;
+
+    for (pos, &referencia) in cadena.iter().enumerate() {
+        if let Some(proximo) = residentes.get(&referencia).copied() {
+            por_distancia.remove(&(proximo, referencia));
+        } else {
+            fallos += 1;
+            if num_marcos == 0 {
+                continue;
+            }
+            if residentes.len() >= num_marcos {
+                if let Some((_, victima)) = por_distancia.pop_last() {
+                    residentes.remove(&victima);
+                }
+            }
+        }
+        residentes.insert(referencia, siguiente[pos]);
+        por_distancia.insert((siguiente[pos], referencia));
+    }
+
+    fallos
+}
+
+/// LFU: reemplaza la página con menos referencias desde su carga
+/// (a igualdad de contador, la menos recientemente usada)
+#[derive(Clone, Default)]
+pub struct Lfu;
+
+impl PoliticaReemplazo for Lfu {
+    fn nombre(&self) -> &str {
+        "LFU"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        vista
+            .ocupados()
+            .filter_map(|idx| {
+                vista
+                    .pagina(idx)
+                    .map(|p| (idx, (p.contador_accesos, p.ultimo_uso)))
+            })
+            .min_by_key(|&(_, clave)| clave)
+            .map(|(idx, _)| idx)
+    }
+}
+
+/// MFU: reemplaza la página con más referencias, suponiendo que la de
+/// contador bajo acaba de cargarse y aún se va a usar
+#[derive(Clone, Default)]
+pub struct Mfu;
+
+impl PoliticaReemplazo for Mfu {
+    fn nombre(&self) -> &str {
+        "MFU"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        vista
+            .ocupados()
+            .filter_map(|idx| {
+                vista
+                    .pagina(idx)
+                    .map(|p| (idx, (p.contador_accesos, std::cmp::Reverse(p.ultimo_uso))))
+            })
+            .max_by_key(|&(_, clave)| clave)
+            .map(|(idx, _)| idx)
+    }
+}
+
+/// Clase NRU de una página: 0 = (R=0, M=0) ... 3 = (R=1, M=1)
+fn clase_nru(pagina: &Pagina) -> u8 {
+    (pagina.referenciada as u8) << 1 | pagina.modificada as u8
+}
+
+/// NRU: elige al azar una página de la clase R/M más baja no vacía.
+/// Los bits R se limpian en cada tick de reloj.
+#[derive(Clone, Default)]
+pub struct Nru;
+
+impl PoliticaReemplazo for Nru {
+    fn nombre(&self) -> &str {
+        "NRU"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn al_tick(&mut self, vista: &mut VistaMemoria) {
+        for proceso in vista.procesos.iter_mut() {
+            for pagina in &mut proceso.paginas {
+                pagina.referenciada = false;
+            }
+        }
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        let clases: Vec<(usize, u8)> = vista
+            .ocupados()
+            .filter_map(|idx| vista.pagina(idx).map(|p| (idx, clase_nru(p))))
+            .collect();
+        let minima = clases.iter().map(|&(_, clase)| clase).min()?;
+        let candidatos: Vec<usize> = clases
+            .into_iter()
+            .filter(|&(_, clase)| clase == minima)
+            .map(|(idx, _)| idx)
+            .collect();
+        candidatos.choose(&mut rand::thread_rng()).copied()
+    }
+}
+
+/// Segunda oportunidad mejorada: recorre el reloj buscando primero (R=0, M=0);
+/// si no hay, busca (R=0, M=1) limpiando bits R a su paso, y repite.
+#[derive(Clone, Default)]
+pub struct RelojMejorado {
+    puntero: usize, // Posición actual de la manecilla
+}
+
+impl PoliticaReemplazo for RelojMejorado {
+    fn nombre(&self) -> &str {
+        "RelojMejorado"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn manecillas(&self) -> Vec<(&'static str, usize)> {
+        vec![("R", self.puntero)]
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        let len = vista.marcos.len();
+        // Tras dos rondas todos los bits R están limpios y hay víctima segura
+        for _ in 0..2 {
+            // Ronda 1: página limpia y no referenciada, sin tocar bits
+            for paso in 0..len {
+                let idx = (self.puntero + paso) % len;
+                if vista.es_candidato(idx) && vista.pagina(idx).is_some_and(|p| clase_nru(p) == 0) {
+                    self.puntero = (idx + 1) % len;
+                    return Some(idx);
+                }
+            }
+            // Ronda 2: página modificada no referenciada, limpiando bits R
+            for paso in 0..len {
+                let idx = (self.puntero + paso) % len;
+                if !vista.es_candidato(idx) {
+                    continue;
+                }
+                if let Some(pagina) = vista.pagina_mut(idx) {
+                    if !pagina.referenciada {
+                        self.puntero = (idx + 1) % len;
+                        return Some(idx);
+                    }
+                    pagina.referenciada = false;
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Envejecimiento (Aging): cada página tiene un registro de N bits que en cada
+/// tick se desplaza a la derecha e incorpora el bit R por la izquierda. Se
+/// reemplaza la página con el registro más bajo (aproximación de LRU).
+#[derive(Clone)]
+pub struct Envejecimiento {
+    bits: u32, // Ancho del registro (1-64)
+}
+
+impl Envejecimiento {
+    pub fn new(bits: u32) -> Self {
+        Self {
+            bits: bits.clamp(1, 64),
+        }
+    }
+}
+
+impl PoliticaReemplazo for Envejecimiento {
+    fn nombre(&self) -> &str {
+        "Envejecimiento"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn al_tick(&mut self, vista: &mut VistaMemoria) {
+        let bit_alto = 1u64 << (self.bits - 1);
+        for proceso in vista.procesos.iter_mut() {
+            for pagina in proceso.paginas.iter_mut().filter(|p| p.marco_id.is_some()) {
+                pagina.edad >>= 1;
+                if pagina.referenciada {
+                    pagina.edad |= bit_alto;
+                }
+                pagina.referenciada = false;
+            }
+        }
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        vista
+            .ocupados()
+            .filter_map(|idx| vista.pagina(idx).map(|p| (idx, (p.edad, p.ultimo_uso))))
+            .min_by_key(|&(_, clave)| clave)
+            .map(|(idx, _)| idx)
+    }
+}
+
+/// WSClock: recorre los marcos como el Reloj, pero solo desaloja páginas fuera
+/// del working set (edad virtual mayor que Δ). Entre ellas prefiere las
+/// limpias; una modificada solo se elige si no hay ninguna limpia vieja.
+#[derive(Clone)]
+pub struct WsClock {
+    ventana: u64,   // Ventana Δ en tiempo virtual del proceso
+    puntero: usize, // Posición actual de la manecilla
+}
+
+impl WsClock {
+    pub fn new(ventana: u64) -> Self {
+        Self {
+            ventana,
+            puntero: 0,
+        }
+    }
+}
+
+impl PoliticaReemplazo for WsClock {
+    fn nombre(&self) -> &str {
+        "WSClock"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn manecillas(&self) -> Vec<(&'static str, usize)> {
+        vec![("W", self.puntero)]
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        let len = vista.marcos.len();
+        let mut vieja_modificada = None;
+        let mut mas_vieja: Option<(u64, usize)> = None;
+
+        for paso in 0..len {
+            let idx = (self.puntero + paso) % len;
+            if !vista.es_candidato(idx) {
+                continue;
+            }
+            let Some(tiempo_virtual) = vista.proceso(idx).map(|p| p.tiempo_virtual) else {
+                continue;
+            };
+            let Some(pagina) = vista.pagina_mut(idx) else {
+                continue;
+            };
+
+            if pagina.referenciada {
+                // Usada desde la última pasada: sigue en el working set
+                pagina.referenciada = false;
+                pagina.ultimo_uso_virtual = Some(tiempo_virtual);
+                continue;
+            }
+
+            // Nunca usada por el proceso: tan vieja como su tiempo virtual
+            let edad = tiempo_virtual.saturating_sub(pagina.ultimo_uso_virtual.unwrap_or(0));
+            if mas_vieja.is_none_or(|(max, _)| edad > max) {
+                mas_vieja = Some((edad, idx));
+            }
+            if edad > self.ventana {
+                if !pagina.modificada {
+                    self.puntero = (idx + 1) % len;
+                    return Some(idx);
+                }
+                vieja_modificada.get_or_insert(idx);
+            }
+        }
+
+        // Vuelta completa sin página limpia fuera del working set
+        let idx = vieja_modificada
+            .or(mas_vieja.map(|(_, idx)| idx))
+            .or_else(|| vista.ocupados().next())?;
+        self.puntero = (idx + 1) % len;
+        Some(idx)
+    }
+}
+
+/// ARC (Megiddo y Modha): T1 guarda páginas vistas una vez y T2 las vistas
+/// varias veces; B1 y B2 recuerdan las expulsadas de cada una. Un acierto en
+/// un fantasma mueve el objetivo `p` (tamaño deseado de T1) hacia esa lista.
+/// En todas las listas el frente es el extremo LRU.
+#[derive(Clone, Default)]
+pub struct PoliticaArc {
+    t1: VecDeque<Clave>,
+    t2: VecDeque<Clave>,
+    b1: VecDeque<Clave>,
+    b2: VecDeque<Clave>,
+    marcos: HashMap<Clave, usize>, // Marco de cada página residente
+    p: usize,                      // Objetivo adaptativo para |T1|
+    capacidad: usize,              // c: número de marcos (se conoce al reemplazar)
+}
+
+impl PoliticaReemplazo for PoliticaArc {
+    fn nombre(&self) -> &str {
+        "ARC"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn al_cargar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        self.marcos.insert(clave, marco.id);
+
+        if quitar(&mut self.b1, clave) {
+            // Acierto fantasma en B1: T1 se quedó corta
+            let delta = (self.b2.len() / (self.b1.len() + 1)).max(1);
+            self.p = (self.p + delta).min(self.capacidad);
+            self.t2.push_back(clave);
+        } else if quitar(&mut self.b2, clave) {
+            // Acierto fantasma en B2: T2 se quedó corta
+            let delta = (self.b1.len() / (self.b2.len() + 1)).max(1);
+            self.p = self.p.saturating_sub(delta);
+            self.t2.push_back(clave);
+        } else {
+            self.t1.push_back(clave);
+        }
+
+        // Limitar el directorio: |T1| + |B1| <= c y |T1| + |T2| + |B1| + |B2| <= 2c
+        if self.capacidad > 0 {
+            while self.t1.len() + self.b1.len() > self.capacidad && self.b1.pop_front().is_some() {}
+            let total = |arc: &Self| arc.t1.len() + arc.t2.len() + arc.b1.len() + arc.b2.len();
+            while total(self) > 2 * self.capacidad && self.b2.pop_front().is_some() {}
+        }
+    }
+
+    fn al_acceder(&mut self, marco: &Marco) {
+        if let Some(clave) = clave_de(marco) {
+            if quitar(&mut self.t1, clave) || quitar(&mut self.t2, clave) {
+                self.t2.push_back(clave);
+            }
+        }
+    }
+
+    fn al_desalojar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        self.marcos.remove(&clave);
+        if quitar(&mut self.t1, clave) {
+            self.b1.push_back(clave);
+        } else if quitar(&mut self.t2, clave) {
+            self.b2.push_back(clave);
+        }
+    }
+
+    fn al_liberar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        self.marcos.remove(&clave);
+        quitar(&mut self.t1, clave);
+        quitar(&mut self.t2, clave);
+        // Los fantasmas de un proceso terminado ya no volverán
+        self.b1.retain(|&(pid, _)| pid != clave.0);
+        self.b2.retain(|&(pid, _)| pid != clave.0);
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        self.capacidad = vista.marcos.len();
+        let entrante_en_b2 = vista.entrante.is_some_and(|c| self.b2.contains(&c));
+
+        // REPLACE(x, p): sacar de T1 si supera su objetivo, si no de T2
+        let desde_t1 = !self.t1.is_empty()
+            && (self.t1.len() > self.p
+                || (entrante_en_b2 && self.t1.len() == self.p)
+                || self.t2.is_empty());
+        let (lista, otra) = if desde_t1 {
+            (&self.t1, &self.t2)
+        } else {
+            (&self.t2, &self.t1)
+        };
+        // Con reemplazo local se toma la candidata más cercana al extremo LRU
+        lista
+            .iter()
+            .chain(otra)
+            .filter_map(|clave| self.marcos.get(clave).copied())
+            .find(|&idx| vista.es_candidato(idx))
+    }
+
+    fn estado(&self) -> Vec<String> {
+        vec![
+            format!("T1: {} | T2: {}", self.t1.len(), self.t2.len()),
+            format!("B1: {} | B2: {}", self.b1.len(), self.b2.len()),
+            format!("p (objetivo T1): {}/{}", self.p, self.capacidad),
+        ]
+    }
+}
+
+/// 2Q (Johnson y Shasha): las páginas nuevas entran en la FIFO A1in; al salir
+/// de ella se recuerdan en A1out. Solo si se vuelven a pedir estando en A1out
+/// pasan a Am, la lista LRU de páginas calientes. Resiste barridos secuenciales.
+#[derive(Clone, Default)]
+pub struct DosColas {
+    a1_in: VecDeque<Clave>,        // FIFO residente de páginas nuevas
+    a1_out: VecDeque<Clave>,       // Fantasmas expulsados de A1in
+    am: VecDeque<Clave>,           // LRU residente de páginas calientes (frente = LRU)
+    marcos: HashMap<Clave, usize>, // Marco de cada página residente
+    capacidad: usize,              // Número de marcos (se conoce al reemplazar)
+}
+
+impl DosColas {
+    /// Kin: tamaño objetivo de A1in (25% de los marcos)
+    fn k_in(&self) -> usize {
+        (self.capacidad / 4).max(1)
+    }
+
+    /// Kout: fantasmas recordados en A1out (50% de los marcos)
+    fn k_out(&self) -> usize {
+        (self.capacidad / 2).max(1)
+    }
+}
+
+impl PoliticaReemplazo for DosColas {
+    fn nombre(&self) -> &str {
+        "2Q"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn al_cargar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        self.marcos.insert(clave, marco.id);
+        if quitar(&mut self.a1_out, clave) {
+            self.am.push_back(clave);
+        } else {
+            self.a1_in.push_back(clave);
+        }
+    }
+
+    fn al_acceder(&mut self, marco: &Marco) {
+        // Los aciertos en A1in no cambian nada (correlacionados con la carga)
+        if let Some(clave) = clave_de(marco) {
+            if quitar(&mut self.am, clave) {
+                self.am.push_back(clave);
+            }
+        }
+    }
+
+    fn al_desalojar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        self.marcos.remove(&clave);
+        if quitar(&mut self.a1_in, clave) {
+            self.a1_out.push_back(clave);
+            while self.a1_out.len() > self.k_out() {
+                self.a1_out.pop_front();
+            }
+        } else {
+            quitar(&mut self.am, clave);
+        }
+    }
+
+    fn al_liberar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        self.marcos.remove(&clave);
+        quitar(&mut self.a1_in, clave);
+        quitar(&mut self.am, clave);
+        self.a1_out.retain(|&(pid, _)| pid != clave.0);
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        self.capacidad = vista.marcos.len();
+        let (lista, otra) = if self.a1_in.len() > self.k_in() || self.am.is_empty() {
+            (&self.a1_in, &self.am)
+        } else {
+            (&self.am, &self.a1_in)
+        };
+        lista
+            .iter()
+            .chain(otra)
+            .filter_map(|clave| self.marcos.get(clave).copied())
+            .find(|&idx| vista.es_candidato(idx))
+    }
+
+    fn estado(&self) -> Vec<String> {
+        vec![
+            format!("A1in: {} (Kin {})", self.a1_in.len(), self.k_in()),
+            format!("A1out: {} (Kout {})", self.a1_out.len(), self.k_out()),
+            format!("Am: {}", self.am.len()),
+        ]
+    }
+}
+
+/// Aleatoria: víctima uniforme entre los marcos ocupados. Con semilla fija
+/// para que dos ejecuciones sean reproducibles; sirve de línea base.
+#[derive(Clone)]
+pub struct Aleatoria {
+    rng: StdRng,
+}
+
+impl Aleatoria {
+    pub fn new(semilla: u64) -> Self {
+        Self {
+            rng: StdRng::seed_from_u64(semilla),
+        }
+    }
+}
+
+impl PoliticaReemplazo for Aleatoria {
+    fn nombre(&self) -> &str {
+        "Aleatoria"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        let ocupados: Vec<usize> = vista.ocupados().collect();
+        ocupados.choose(&mut self.rng).copied()
+    }
+}
+
+/// Entrada del reloj de CLOCK-Pro: páginas residentes y fantasmas en prueba
+#[derive(Clone)]
+struct EntradaClockPro {
+    clave: Clave,
+    marco: Option<usize>, // None = no residente (solo metadatos durante la prueba)
+    caliente: bool,
+    en_prueba: bool,
+    referenciada: bool,
+}
+
+/// CLOCK-Pro (Jiang, Chen y Zhang): un único reloj con páginas calientes,
+/// frías residentes y frías no residentes en periodo de prueba. La manecilla
+/// fría busca víctimas, la caliente enfría páginas calientes sin uso y la de
+/// prueba olvida fantasmas caducados. Un fallo sobre un fantasma en prueba
+/// agranda `objetivo_frias`; una prueba que caduca sin uso lo reduce.
+#[derive(Clone, Default)]
+pub struct ClockPro {
+    lista: Vec<EntradaClockPro>,
+    mano_caliente: usize,
+    mano_fria: usize,
+    mano_prueba: usize,
+    capacidad: usize,      // Número de marcos (se conoce al reemplazar)
+    objetivo_frias: usize, // m_c: marcos reservados a páginas frías
+}
+
+impl ClockPro {
+    fn posicion(&self, clave: Clave) -> Option<usize> {
+        self.lista.iter().position(|e| e.clave == clave)
+    }
+
+    fn manos(&mut self) -> [&mut usize; 3] {
+        [
+            &mut self.mano_caliente,
+            &mut self.mano_fria,
+            &mut self.mano_prueba,
+        ]
+    }
+
+    /// Inserta en la cabeza del reloj (justo detrás de la manecilla caliente)
+    fn insertar_en_cabeza(&mut self, entrada: EntradaClockPro) {
+        let pos = self.mano_caliente.min(self.lista.len());
+        self.lista.insert(pos, entrada);
+        for mano in self.manos() {
+            if *mano >= pos {
+                *mano += 1;
+            }
+        }
+        let len = self.lista.len();
+        for mano in self.manos() {
+            *mano %= len;
+        }
+    }
+
+    fn quitar_entrada(&mut self, pos: usize) {
+        self.lista.remove(pos);
+        let len = self.lista.len().max(1);
+        for mano in self.manos() {
+            if *mano > pos {
+                *mano -= 1;
+            }
+            *mano %= len;
+        }
+    }
+
+    fn calientes(&self) -> usize {
+        self.lista.iter().filter(|e| e.caliente).count()
+    }
+
+    fn no_residentes(&self) -> usize {
+        self.lista.iter().filter(|e| e.marco.is_none()).count()
+    }
+
+    /// Una prueba caducó sin que la página volviera: menos marcos para frías
+    fn prueba_caducada(&mut self) {
+        self.objetivo_frias = self.objetivo_frias.saturating_sub(1).max(1);
+    }
+
+    /// Manecilla caliente: avanza hasta enfriar una página caliente no referenciada
+    fn mover_mano_caliente(&mut self) {
+        for _ in 0..self.lista.len() * 2 {
+            if self.lista.is_empty() {
+                return;
+            }
+            let pos = self.mano_caliente;
+            let entrada = &mut self.lista[pos];
+            if entrada.caliente {
+                if entrada.referenciada {
+                    entrada.referenciada = false;
+                } else {
+                    entrada.caliente = false;
+                    entrada.en_prueba = false;
+                    self.mano_caliente = (pos + 1) % self.lista.len();
+                    return;
+                }
+            } else if entrada.en_prueba {
+                // Al pasar la manecilla caliente termina el periodo de prueba
+                entrada.en_prueba = false;
+                if entrada.marco.is_none() {
+                    self.quitar_entrada(pos);
+                    self.prueba_caducada();
+                    continue;
+                }
+            }
+            self.mano_caliente = (pos + 1) % self.lista.len();
+        }
+    }
+
+    /// Manecilla de prueba: olvida el siguiente fantasma en prueba
+    fn mover_mano_prueba(&mut self) {
+        for _ in 0..self.lista.len() {
+            let pos = self.mano_prueba;
+            let entrada = &mut self.lista[pos];
+            if !entrada.caliente && entrada.en_prueba {
+                entrada.en_prueba = false;
+                if entrada.marco.is_none() {
+                    self.quitar_entrada(pos);
+                    self.prueba_caducada();
+                    return;
+                }
+            }
+            self.mano_prueba = (pos + 1) % self.lista.len();
+        }
+    }
+
+    /// Mantiene las calientes dentro de su reparto c - m_c
+    fn equilibrar_calientes(&mut self) {
+        if self.capacidad == 0 {
+            return;
+        }
+        let maximo = self.capacidad.saturating_sub(self.objetivo_frias);
+        for _ in 0..self.capacidad {
+            if self.calientes() <= maximo {
+                break;
+            }
+            self.mover_mano_caliente();
+        }
+    }
+
+    /// Primer marco residente a partir de una posición del reloj
+    fn marco_desde(&self, pos: usize) -> Option<usize> {
+        let len = self.lista.len();
+        (0..len).find_map(|paso| self.lista[(pos + paso) % len].marco)
+    }
+}
+
+impl PoliticaReemplazo for ClockPro {
+    fn nombre(&self) -> &str {
+        "CLOCK-Pro"
+    }
+
+    fn instantanea(&self) -> Option<Box<dyn PoliticaReemplazo>> {
+        Some(Box::new(self.clone()))
+    }
+
+    fn al_cargar(&mut self, marco: &Marco) {
+        let Some(clave) = clave_de(marco) else {
+            return;
+        };
+        match self.posicion(clave) {
+            Some(pos) if self.lista[pos].marco.is_none() => {
+                // Fallo sobre un fantasma en prueba: su distancia de reuso es
+                // corta, entra caliente y las frías ganan un marco
+                self.objetivo_frias = (self.objetivo_frias + 1).min(self.capacidad.max(2) - 1);
+                self.quitar_entrada(pos);
+                self.insertar_en_cabeza(EntradaClockPro {
+                    clave,
+                    marco: Some(marco.id),
+                    caliente: true,
+                    en_prueba: false,
+                    referenciada: false,
+                });
+                self.equilibrar_calientes();
+            }
+            Some(pos) => self.lista[pos].marco = Some(marco.id),
+            None => self.insertar_en_cabeza(EntradaClockPro {
+                clave,
+                marco: Some(marco.id),
+                caliente: false,
+                en_prueba: true,
+                referenciada: false,
+            }),
+        }
+    }
+
+    fn al_acceder(&mut self, marco: &Marco) {
+        if let Some(pos) = clave_de(marco).and_then(|c| self.posicion(c)) {
+            self.lista[pos].referenciada = true;
+        }
+    }
+
+    fn al_desalojar(&mut self, marco: &Marco) {
+        let Some(pos) = clave_de(marco).and_then(|c| self.posicion(c)) else {
+            return;
+        };
+        let entrada = &mut self.lista[pos];
+        if entrada.en_prueba && !entrada.caliente {
+            // Sigue en prueba como fantasma no residente
+            entrada.marco = None;
+            entrada.referenciada = false;
+            if self.no_residentes() > self.capacidad {
+                self.mover_mano_prueba();
+            }
+        } else {
+            self.quitar_entrada(pos);
+        }
+    }
+
+    fn al_liberar(&mut self, marco: &Marco) {
+        let Some(pid) = marco.proceso_id else {
+            return;
+        };
+        if let Some(pos) = clave_de(marco).and_then(|c| self.posicion(c)) {
+            self.quitar_entrada(pos);
+        }
+        // Los fantasmas de un proceso terminado ya no volverán
+        while let Some(pos) = self
+            .lista
+            .iter()
+            .position(|e| e.clave.0 == pid && e.marco.is_none())
+        {
+            self.quitar_entrada(pos);
+        }
+    }
+
+    fn elegir_victima(&mut self, vista: &mut VistaMemoria) -> Option<usize> {
+        self.capacidad = vista.marcos.len();
+        self.objetivo_frias = self.objetivo_frias.clamp(1, self.capacidad.max(1));
+
+        for _ in 0..self.lista.len() * 3 {
+            if self.lista.is_empty() {
+                break;
+            }
+            let pos = self.mano_fria;
+            let len = self.lista.len();
+            let candidata = self.lista[pos]
+                .marco
+                .is_some_and(|marco| vista.es_candidato(marco));
+            let entrada = &mut self.lista[pos];
+            if !candidata || entrada.caliente {
+                self.mano_fria = (pos + 1) % len;
+                continue;
+            }
+            if !entrada.referenciada {
+                self.mano_fria = (pos + 1) % len;
+                return entrada.marco;
+            }
+            entrada.referenciada = false;
+            if entrada.en_prueba {
+                // Reusada durante su prueba: pasa a caliente
+                entrada.caliente = true;
+                entrada.en_prueba = false;
+                self.mano_fria = (pos + 1) % len;
+                self.equilibrar_calientes();
+            } else {
+                entrada.en_prueba = true;
+                self.mano_fria = (pos + 1) % len;
+            }
+        }
+
+        // Solo quedan calientes: enfriar una y tomar el primer residente
+        self.mover_mano_caliente();
+        let len = self.lista.len();
+        (0..len)
+            .filter_map(|paso| self.lista[(self.mano_fria + paso) % len].marco)
+            .find(|&marco| vista.es_candidato(marco))
+    }
+
+    fn estado(&self) -> Vec<String> {
+        let calientes = self.calientes();
+        let no_residentes = self.no_residentes();
+        let frias = self.lista.len() - calientes - no_residentes;
+        vec![
+            format!("Calientes: {} | Frías: {}", calientes, frias),
+            format!("Fantasmas en prueba: {}", no_residentes),
+            format!(
+                "m_c (objetivo frías): {}/{}",
+                self.objetivo_frias, self.capacidad
+            ),
+        ]
+    }
+
+    fn manecillas(&self) -> Vec<(&'static str, usize)> {
+        [
+            ("H", self.mano_caliente),
+            ("C", self.mano_fria),
+            ("T", self.mano_prueba),
+        ]
+        .into_iter()
+        .filter_map(|(etiqueta, pos)| self.marco_desde(pos).map(|marco| (etiqueta, marco)))
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AlgoritmoReemplazo, GestorMemoria, VENTANA_OPTIMO};
+
+    #[test]
+    fn optimo_sobre_la_cadena_clasica() {
+        let cadena: Vec<Clave> = [7, 0, 1, 2, 0, 3, 0, 4, 2, 3, 0, 3, 2]
+            .into_iter()
+            .map(|pagina| (1, pagina))
+            .collect();
+        assert_eq!(fallos_optimos(&cadena, 3), 7);
+        // Con un marco más solo quedan los fallos obligatorios (6 páginas distintas)
+        assert_eq!(fallos_optimos(&cadena, 4), 6);
+        assert_eq!(fallos_optimos(&cadena, 0), cadena.len());
+    }
+
+    #[test]
+    fn optimo_del_gestor_limitado_a_la_ventana() {
+        // 4 marcos y un proceso de 8 páginas recorrido en bucle
+        let mut gestor = GestorMemoria::new(16, 64, 4, AlgoritmoReemplazo::LRU);
+        gestor
+            .asignar_proceso(Proceso::new(1, "bucle", 32, (0, 0, 0)))
+            .unwrap();
+        for i in 0..3 * VENTANA_OPTIMO {
+            gestor
+                .acceder_pagina(1, i % 8, TipoAcceso::Lectura)
+                .unwrap();
+        }
+
+        assert_eq!(gestor.historial_referencias.len(), VENTANA_OPTIMO);
+        let ventana: Vec<Clave> = gestor
+            .historial_referencias
+            .iter()
+            .map(|&(pid, pagina, _)| (pid, pagina))
+            .collect();
+        let optimos = gestor.fallos_optimos();
+        assert_eq!(
+            optimos,
+            fallos_optimos(&ventana, gestor.marcos_ram.len()) - 8
+        );
+        // LRU falla en cada referencia de un bucle mayor que la RAM
+        assert_eq!(gestor.fallos_ventana(), VENTANA_OPTIMO - 8);
+        assert!(optimos < gestor.fallos_ventana());
+    }
+}
@@ -0,0 +1,284 @@
+// --- ASIGNADOR SLAB DEL NÚCLEO ---
+
+/// Cachés del núcleo por defecto: (nombre, tamaño del objeto en bytes)
+pub const CACHES_POR_DEFECTO: [(&str, usize); 4] = [
+    ("task_struct", 6144),
+    ("mm_struct", 1024),
+    ("inode", 600),
+    ("dentry", 192),
+];
+
+/// Cachés por defecto cuyos objetos caben en un marco de `tamaño_marco` bytes
+pub fn caches_por_defecto(tamaño_marco: usize) -> Vec<(&'static str, usize)> {
+    CACHES_POR_DEFECTO
+        .into_iter()
+        .filter(|&(_, tamaño)| tamaño <= tamaño_marco)
+        .collect()
+}
+
+/// Ocupación de un slab
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EstadoSlab {
+    Lleno,   // Todos los objetos en uso
+    Parcial, // Algunos objetos en uso
+    Vacio,   // Ningún objeto en uso (el marco se puede devolver)
+}
+
+/// Slab: un marco de RAM partido en objetos del mismo tamaño
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Slab {
+    pub marco: usize,    // Marco de `marcos_ram` que ocupa
+    ocupados: Vec<bool>, // Un indicador por objeto (true = en uso)
+}
+
+impl Slab {
+    pub fn en_uso(&self) -> usize {
+        self.ocupados.iter().filter(|&&o| o).count()
+    }
+
+    pub fn capacidad(&self) -> usize {
+        self.ocupados.len()
+    }
+
+    pub fn estado(&self) -> EstadoSlab {
+        match self.en_uso() {
+            0 => EstadoSlab::Vacio,
+            n if n == self.capacidad() => EstadoSlab::Lleno,
+            _ => EstadoSlab::Parcial,
+        }
+    }
+
+    /// Índices de los objetos en uso
+    pub fn objetos(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.ocupados.len()).filter(|&i| self.ocupados[i])
+    }
+}
+
+/// Caché de objetos de un tipo: todos sus slabs reparten el marco en
+/// `objetos_por_slab` huecos de `tamaño_objeto` bytes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheSlab {
+    pub nombre: String,
+    pub tamaño_objeto: usize,    // Bytes de cada objeto
+    pub objetos_por_slab: usize, // Objetos que caben en un marco (0 = no cabe ninguno)
+    pub slabs: Vec<Slab>,
+}
+
+impl CacheSlab {
+    pub fn new(nombre: &str, tamaño_objeto: usize, tamaño_marco: usize) -> Self {
+        Self {
+            nombre: nombre.to_string(),
+            tamaño_objeto,
+            objetos_por_slab: tamaño_marco.checked_div(tamaño_objeto).unwrap_or(0),
+            slabs: Vec::new(),
+        }
+    }
+
+    /// Slabs de la caché en el estado indicado
+    pub fn contar(&self, estado: EstadoSlab) -> usize {
+        self.slabs.iter().filter(|s| s.estado() == estado).count()
+    }
+
+    pub fn objetos_en_uso(&self) -> usize {
+        self.slabs.iter().map(Slab::en_uso).sum()
+    }
+
+    /// Porcentaje de los objetos de la caché que están en uso
+    pub fn utilizacion(&self) -> f64 {
+        let capacidad = self.slabs.len() * self.objetos_por_slab;
+        if capacidad == 0 {
+            return 0.0;
+        }
+        (self.objetos_en_uso() as f64 / capacidad as f64) * 100.0
+    }
+
+    /// Coloca un objeto en un slab parcial o, si no hay, en uno vacío.
+    /// Devuelve (marco, índice del objeto) o None si todos están llenos.
+    pub fn reservar(&mut self) -> Option<(usize, usize)> {
+        let slab = self
+            .slabs
+            .iter_mut()
+            .filter(|s| s.estado() != EstadoSlab::Lleno)
+            .min_by_key(|s| s.estado() == EstadoSlab::Vacio)?;
+        let indice = slab.ocupados.iter().position(|&o| !o)?;
+        slab.ocupados[indice] = true;
+        Some((slab.marco, indice))
+    }
+
+    /// Añade a la caché un slab vacío sobre el marco indicado
+    pub fn crecer(&mut self, marco: usize) {
+        self.slabs.push(Slab {
+            marco,
+            ocupados: vec![false; self.objetos_por_slab],
+        });
+    }
+
+    /// Devuelve el objeto a su slab. Devuelve si estaba en uso.
+    pub fn liberar(&mut self, marco: usize, indice: usize) -> bool {
+        self.slabs
+            .iter_mut()
+            .find(|s| s.marco == marco)
+            .and_then(|s| s.ocupados.get_mut(indice))
+            .is_some_and(std::mem::take)
+    }
+
+    /// Quita los slabs vacíos (como mucho `maximo`) y devuelve sus marcos
+    pub fn reducir(&mut self, maximo: usize) -> Vec<usize> {
+        let mut marcos = Vec::new();
+        self.slabs.retain(|s| {
+            if marcos.len() < maximo && s.estado() == EstadoSlab::Vacio {
+                marcos.push(s.marco);
+                false
+            } else {
+                true
+            }
+        });
+        marcos
+    }
+}
+
+/// Capa slab del núcleo: cachés con nombre que toman marcos de la RAM
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsignadorSlab {
+    pub caches: Vec<CacheSlab>,
+}
+
+impl AsignadorSlab {
+    pub fn new(tamaño_marco: usize, caches: &[(&str, usize)]) -> Self {
+        Self {
+            caches: caches
+                .iter()
+                .map(|&(nombre, tamaño)| CacheSlab::new(nombre, tamaño, tamaño_marco))
+                .collect(),
+        }
+    }
+
+    /// Posición de la caché con ese nombre
+    pub fn indice(&self, nombre: &str) -> Option<usize> {
+        self.caches.iter().position(|c| c.nombre == nombre)
+    }
+
+    /// Marcos que ocupan los slabs de todas las cachés
+    pub fn marcos(&self) -> impl Iterator<Item = usize> + '_ {
+        self.caches
+            .iter()
+            .flat_map(|c| c.slabs.iter().map(|s| s.marco))
+    }
+
+    /// Caché a la que pertenece el marco
+    pub fn cache_de_marco(&self, marco: usize) -> Option<&CacheSlab> {
+        self.caches
+            .iter()
+            .find(|c| c.slabs.iter().any(|s| s.marco == marco))
+    }
+
+    /// Objetos en uso: (índice de caché, marco, índice del objeto)
+    pub fn objetos(&self) -> Vec<(usize, usize, usize)> {
+        self.caches
+            .iter()
+            .enumerate()
+            .flat_map(|(c, cache)| {
+                cache
+                    .slabs
+                    .iter()
+                    .flat_map(move |s| s.objetos().map(move |i| (c, s.marco, i)))
+            })
+            .collect()
+    }
+
+    /// Vacía todas las cachés y devuelve los marcos que ocupaban
+    pub fn vaciar(&mut self) -> Vec<usize> {
+        let marcos = self.marcos().collect();
+        for cache in &mut self.caches {
+            cache.slabs.clear();
+        }
+        marcos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn un_slab_pasa_de_vacio_a_parcial_y_a_lleno() {
+        // Marcos de 4 KB: caben 4 objetos de 1000 B
+        let mut cache = CacheSlab::new("mm_struct", 1000, 4096);
+        assert_eq!(cache.objetos_por_slab, 4);
+        assert_eq!(cache.reservar(), None);
+
+        cache.crecer(7);
+        assert_eq!(cache.slabs[0].estado(), EstadoSlab::Vacio);
+        assert_eq!(cache.reservar(), Some((7, 0)));
+        assert_eq!(cache.slabs[0].estado(), EstadoSlab::Parcial);
+        for indice in 1..4 {
+            assert_eq!(cache.reservar(), Some((7, indice)));
+        }
+        assert_eq!(cache.slabs[0].estado(), EstadoSlab::Lleno);
+        assert_eq!(cache.reservar(), None);
+
+        assert!(cache.liberar(7, 2));
+        assert!(!cache.liberar(7, 2));
+        assert_eq!(cache.slabs[0].estado(), EstadoSlab::Parcial);
+        assert_eq!(cache.utilizacion(), 75.0);
+    }
+
+    #[test]
+    fn reservar_prefiere_un_slab_parcial() {
+        let mut cache = CacheSlab::new("dentry", 2048, 4096);
+        cache.crecer(1);
+        cache.crecer(2);
+        assert_eq!(cache.reservar(), Some((1, 0)));
+        // El slab 2 está vacío, pero el 1 tiene hueco
+        assert_eq!(cache.reservar(), Some((1, 1)));
+        assert_eq!(cache.reservar(), Some((2, 0)));
+        assert_eq!(
+            [EstadoSlab::Lleno, EstadoSlab::Parcial, EstadoSlab::Vacio].map(|e| cache.contar(e)),
+            [1, 1, 0]
+        );
+    }
+
+    #[test]
+    fn reducir_solo_devuelve_slabs_vacios() {
+        let mut cache = CacheSlab::new("inode", 600, 4096);
+        for marco in [3, 5, 8] {
+            cache.crecer(marco);
+        }
+        cache.reservar();
+        assert_eq!(cache.reducir(1), [5]);
+        assert_eq!(cache.reducir(usize::MAX), [8]);
+        assert_eq!(cache.slabs.len(), 1);
+
+        cache.liberar(3, 0);
+        assert_eq!(cache.reducir(usize::MAX), [3]);
+        assert!(cache.slabs.is_empty());
+    }
+
+    #[test]
+    fn objetos_que_no_caben_en_un_marco() {
+        let asignador = AsignadorSlab::new(4096, &CACHES_POR_DEFECTO);
+        let task_struct = asignador.indice("task_struct").unwrap();
+        assert_eq!(asignador.caches[task_struct].objetos_por_slab, 0);
+        assert_eq!(asignador.indice("mm_struct"), Some(1));
+        assert_eq!(asignador.indice("kmalloc"), None);
+
+        let filtradas = AsignadorSlab::new(4096, &caches_por_defecto(4096));
+        assert_eq!(filtradas.indice("task_struct"), None);
+        assert_eq!(filtradas.caches.len(), 3);
+    }
+
+    #[test]
+    fn vaciar_devuelve_todos_los_marcos() {
+        let mut asignador = AsignadorSlab::new(4096, &[("a", 512), ("b", 256)]);
+        asignador.caches[0].crecer(4);
+        asignador.caches[1].crecer(9);
+        asignador.caches[1].reservar();
+        assert_eq!(asignador.objetos(), [(1, 9, 0)]);
+        assert_eq!(
+            asignador.cache_de_marco(9).map(|c| c.nombre.as_str()),
+            Some("b")
+        );
+        assert_eq!(asignador.vaciar(), [4, 9]);
+        assert_eq!(asignador.marcos().count(), 0);
+    }
+}
@@ -0,0 +1,443 @@
+use std::collections::BTreeSet;
+
+// --- ORGANIZACIÓN DE LAS TABLAS DE PÁGINAS ---
+
+/// Niveles máximos de la tabla jerárquica (estilo x86 de 32 bits + PAE)
+pub const MAX_NIVELES: usize = 3;
+
+/// Tabla de páginas jerárquica: el número de página virtual se divide en un
+/// índice por nivel, de la tabla exterior a la interior. Con un solo nivel es
+/// la tabla lineal clásica, que reserva una entrada por cada página del
+/// espacio virtual aunque el proceso no la use.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParametrosTablaPaginas {
+    pub bits_por_nivel: Vec<u32>, // Bits del número de página por nivel (exterior primero)
+    pub tamaño_entrada: usize,    // Bytes de cada entrada (PTE)
+}
+
+impl Default for ParametrosTablaPaginas {
+    fn default() -> Self {
+        Self {
+            bits_por_nivel: vec![20],
+            tamaño_entrada: 4,
+        }
+    }
+}
+
+impl ParametrosTablaPaginas {
+    /// Reparte `bits_totales` entre `niveles` lo más parejo posible
+    /// (los niveles exteriores se quedan con los bits sobrantes)
+    pub fn repartida(bits_totales: u32, niveles: usize, tamaño_entrada: usize) -> Self {
+        let niveles = niveles
+            .clamp(1, MAX_NIVELES)
+            .min(bits_totales.max(1) as usize) as u32;
+        let bits_por_nivel = (0..niveles)
+            .map(|i| bits_totales / niveles + u32::from(i < bits_totales % niveles))
+            .collect();
+        Self {
+            bits_por_nivel,
+            tamaño_entrada,
+        }
+    }
+
+    pub fn niveles(&self) -> usize {
+        self.bits_por_nivel.len()
+    }
+
+    /// Bits totales del número de página virtual
+    pub fn bits_totales(&self) -> u32 {
+        self.bits_por_nivel.iter().sum()
+    }
+
+    /// Páginas que caben en el espacio de direcciones virtual
+    pub fn paginas_direccionables(&self) -> usize {
+        1usize << self.bits_totales()
+    }
+
+    /// Índice de la página dentro de cada nivel (exterior primero)
+    pub fn indices(&self, pagina: usize) -> Vec<usize> {
+        let mut restantes = self.bits_totales();
+        self.bits_por_nivel
+            .iter()
+            .map(|&bits| {
+                restantes -= bits;
+                (pagina >> restantes) & ((1 << bits) - 1)
+            })
+            .collect()
+    }
+
+    /// Tablas que hay que reservar en cada nivel para las páginas indicadas:
+    /// una tabla de nivel `i` por cada prefijo distinto de los niveles anteriores
+    pub fn tablas_por_nivel(&self, paginas: impl Iterator<Item = usize> + Clone) -> Vec<usize> {
+        let mut restantes = self.bits_totales();
+        self.bits_por_nivel
+            .iter()
+            .map(|&bits| {
+                let prefijos: BTreeSet<usize> = paginas.clone().map(|p| p >> restantes).collect();
+                restantes -= bits;
+                prefijos.len()
+            })
+            .collect()
+    }
+
+    /// Bytes ocupados por las tablas de páginas de un proceso
+    pub fn memoria_tablas(&self, paginas: impl Iterator<Item = usize> + Clone) -> usize {
+        self.tablas_por_nivel(paginas)
+            .iter()
+            .zip(&self.bits_por_nivel)
+            .map(|(tablas, &bits)| tablas * (1 << bits) * self.tamaño_entrada)
+            .sum()
+    }
+
+    /// Bytes que ocuparía una tabla lineal de un nivel para el mismo espacio virtual
+    pub fn memoria_lineal(&self) -> usize {
+        self.paginas_direccionables() * self.tamaño_entrada
+    }
+}
+
+/// Estructura que se recorre para traducir tras un fallo de TLB
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrganizacionTabla {
+    Jerarquica, // Tabla por proceso (lineal o multinivel según PAGE_TABLE_BITS)
+    Invertida,  // Una entrada por marco + tabla ancla hash por (PID, página)
+    Hash,       // Tabla hash de páginas virtuales con cadenas de colisión
+}
+
+impl OrganizacionTabla {
+    pub const TODOS: [OrganizacionTabla; 3] = [
+        OrganizacionTabla::Jerarquica,
+        OrganizacionTabla::Invertida,
+        OrganizacionTabla::Hash,
+    ];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            OrganizacionTabla::Jerarquica => "Jerarquica",
+            OrganizacionTabla::Invertida => "Invertida",
+            OrganizacionTabla::Hash => "Hash",
+        }
+    }
+
+    /// Posición de la organización en `TODOS`
+    pub fn indice(self) -> usize {
+        match self {
+            OrganizacionTabla::Jerarquica => 0,
+            OrganizacionTabla::Invertida => 1,
+            OrganizacionTabla::Hash => 2,
+        }
+    }
+
+    /// Busca una organización por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|org| org.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+}
+
+/// Coste acumulado de las búsquedas en una organización de tabla
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CosteBusqueda {
+    pub consultas: usize, // Búsquedas realizadas (fallos de TLB)
+    pub accesos: usize,   // Accesos a memoria sumando todas las búsquedas
+    pub maximo: usize,    // Accesos de la búsqueda más cara
+}
+
+impl CosteBusqueda {
+    pub fn registrar(&mut self, accesos: usize) {
+        self.consultas += 1;
+        self.accesos += accesos;
+        self.maximo = self.maximo.max(accesos);
+    }
+
+    /// Accesos a memoria por búsqueda en promedio
+    pub fn media(&self) -> f64 {
+        if self.consultas == 0 {
+            return 0.0;
+        }
+        self.accesos as f64 / self.consultas as f64
+    }
+}
+
+/// Cubeta de la clave (PID, página) en una tabla de `cubetas` posiciones
+fn cubeta(pid: usize, pagina: usize, cubetas: usize) -> usize {
+    (pid.wrapping_mul(0x9E37_79B9) ^ pagina) % cubetas.max(1)
+}
+
+/// Entrada de la tabla invertida: qué página ocupa el marco
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct EntradaInvertida {
+    pid: usize,
+    pagina: usize,
+    siguiente: Option<usize>, // Siguiente marco de la misma cadena de colisión
+}
+
+/// Tabla de páginas invertida: una entrada por marco físico (como
+/// `marcos_ram`) y una tabla ancla indexada por hash de (PID, página) que
+/// apunta al primer marco de cada cadena de colisión
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TablaInvertida {
+    anclas: Vec<Option<usize>>,
+    entradas: Vec<Option<EntradaInvertida>>,
+}
+
+impl TablaInvertida {
+    pub fn new(marcos: usize) -> Self {
+        Self {
+            anclas: vec![None; marcos],
+            entradas: vec![None; marcos],
+        }
+    }
+
+    /// Registra que `marco` contiene la página (al principio de su cadena)
+    pub fn insertar(&mut self, marco: usize, pid: usize, pagina: usize) {
+        self.quitar(marco);
+        let c = cubeta(pid, pagina, self.anclas.len());
+        self.entradas[marco] = Some(EntradaInvertida {
+            pid,
+            pagina,
+            siguiente: self.anclas[c],
+        });
+        self.anclas[c] = Some(marco);
+    }
+
+    /// Vacía la entrada de `marco` y la desengancha de su cadena
+    pub fn quitar(&mut self, marco: usize) {
+        let Some(entrada) = self.entradas.get_mut(marco).and_then(Option::take) else {
+            return;
+        };
+        let c = cubeta(entrada.pid, entrada.pagina, self.anclas.len());
+        if self.anclas[c] == Some(marco) {
+            self.anclas[c] = entrada.siguiente;
+            return;
+        }
+        let mut actual = self.anclas[c];
+        while let Some(idx) = actual {
+            let Some(previa) = self.entradas[idx].as_mut() else {
+                break;
+            };
+            if previa.siguiente == Some(marco) {
+                previa.siguiente = entrada.siguiente;
+                return;
+            }
+            actual = previa.siguiente;
+        }
+    }
+
+    /// Busca el marco que contiene la página. Devuelve también los accesos a
+    /// memoria necesarios: la tabla ancla y cada entrada visitada de la cadena.
+    pub fn buscar(&self, pid: usize, pagina: usize) -> (Option<usize>, usize) {
+        let mut accesos = 1;
+        let mut actual = self
+            .anclas
+            .get(cubeta(pid, pagina, self.anclas.len()))
+            .copied()
+            .flatten();
+        while let Some(marco) = actual {
+            accesos += 1;
+            let Some(entrada) = self.entradas[marco] else {
+                break;
+            };
+            if (entrada.pid, entrada.pagina) == (pid, pagina) {
+                return (Some(marco), accesos);
+            }
+            actual = entrada.siguiente;
+        }
+        (None, accesos)
+    }
+
+    /// Página registrada en el marco
+    pub fn pagina_en(&self, marco: usize) -> Option<(usize, usize)> {
+        self.entradas
+            .get(marco)
+            .copied()
+            .flatten()
+            .map(|e| (e.pid, e.pagina))
+    }
+
+    /// Rehace la tabla a partir del contenido de la RAM
+    pub fn reconstruir(&mut self, ocupacion: impl Iterator<Item = (usize, usize, usize)>) {
+        let marcos = self.entradas.len();
+        *self = Self::new(marcos);
+        for (marco, pid, pagina) in ocupacion {
+            self.insertar(marco, pid, pagina);
+        }
+    }
+
+    /// Bytes de la tabla: cada entrada guarda etiqueta (PID, página) y
+    /// enlace; cada ancla, un puntero
+    pub fn memoria(&self, tamaño_entrada: usize) -> usize {
+        (self.entradas.len() * 2 + self.anclas.len()) * tamaño_entrada
+    }
+
+    /// Longitud de la cadena de colisión más larga
+    pub fn cadena_maxima(&self) -> usize {
+        (0..self.anclas.len())
+            .map(|c| {
+                let mut longitud = 0;
+                let mut actual = self.anclas[c];
+                while let Some(entrada) = actual.and_then(|m| self.entradas[m]) {
+                    longitud += 1;
+                    actual = entrada.siguiente;
+                }
+                longitud
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// Nodo de la tabla hash: página virtual y marco que la contiene
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct NodoHash {
+    pid: usize,
+    pagina: usize,
+    marco: Option<usize>, // None = página no presente (en Swap o sin cargar)
+}
+
+/// Tabla de páginas hash: un nodo por página virtual de cada proceso,
+/// encadenado en la cubeta de su hash. El nodo guarda el marco de la
+/// página, así que la búsqueda se resuelve sin consultar otra tabla.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TablaHash {
+    cubetas: Vec<Vec<NodoHash>>,
+}
+
+impl TablaHash {
+    pub fn new(cubetas: usize) -> Self {
+        Self {
+            cubetas: vec![Vec::new(); cubetas.max(1)],
+        }
+    }
+
+    pub fn cubetas(&self) -> usize {
+        self.cubetas.len()
+    }
+
+    fn nodo_mut(&mut self, pid: usize, pagina: usize) -> Option<&mut NodoHash> {
+        let c = cubeta(pid, pagina, self.cubetas.len());
+        self.cubetas[c]
+            .iter_mut()
+            .find(|n| (n.pid, n.pagina) == (pid, pagina))
+    }
+
+    /// Añade el nodo de la página al principio de su cadena
+    pub fn insertar(&mut self, pid: usize, pagina: usize, marco: Option<usize>) {
+        let c = cubeta(pid, pagina, self.cubetas.len());
+        self.cubetas[c].insert(0, NodoHash { pid, pagina, marco });
+    }
+
+    /// Actualiza el marco de la página (None al desalojarla)
+    pub fn asignar_marco(&mut self, pid: usize, pagina: usize, marco: Option<usize>) {
+        if let Some(nodo) = self.nodo_mut(pid, pagina) {
+            nodo.marco = marco;
+        }
+    }
+
+    /// Elimina los nodos de un proceso terminado
+    pub fn quitar_proceso(&mut self, pid: usize) {
+        for cadena in &mut self.cubetas {
+            cadena.retain(|n| n.pid != pid);
+        }
+    }
+
+    /// Si la página tiene nodo en la tabla
+    pub fn contiene(&self, pid: usize, pagina: usize) -> bool {
+        self.cubetas[cubeta(pid, pagina, self.cubetas.len())]
+            .iter()
+            .any(|n| (n.pid, n.pagina) == (pid, pagina))
+    }
+
+    /// Busca el marco de la página. Devuelve también los accesos a memoria:
+    /// la cubeta y cada nodo visitado de la cadena (el último trae el marco).
+    pub fn buscar(&self, pid: usize, pagina: usize) -> (Option<usize>, usize) {
+        let cadena = &self.cubetas[cubeta(pid, pagina, self.cubetas.len())];
+        match cadena
+            .iter()
+            .position(|n| (n.pid, n.pagina) == (pid, pagina))
+        {
+            Some(pos) => (cadena[pos].marco, pos + 2),
+            None => (None, cadena.len() + 1),
+        }
+    }
+
+    /// Nodos en la tabla (páginas virtuales de todos los procesos)
+    pub fn nodos(&self) -> usize {
+        self.cubetas.iter().map(Vec::len).sum()
+    }
+
+    /// Bytes de la tabla: cada nodo guarda etiqueta, marco y enlace; cada
+    /// cubeta, un puntero
+    pub fn memoria(&self, tamaño_entrada: usize) -> usize {
+        (self.nodos() * 3 + self.cubetas.len()) * tamaño_entrada
+    }
+
+    /// Longitud de la cadena de colisión más larga
+    pub fn cadena_maxima(&self) -> usize {
+        self.cubetas.iter().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tabla invertida de 4 marcos con las páginas 0, 4 y 8 del PID 0 en
+    /// los marcos 0, 1 y 2: las tres caen en la cubeta 0 (cadena 2 -> 1 -> 0)
+    fn cadena_de_tres() -> TablaInvertida {
+        let mut tabla = TablaInvertida::new(4);
+        for (marco, pagina) in [(0, 0), (1, 4), (2, 8)] {
+            tabla.insertar(marco, 0, pagina);
+        }
+        tabla
+    }
+
+    #[test]
+    fn buscar_recorre_la_cadena_de_colision() {
+        let tabla = cadena_de_tres();
+        assert_eq!(tabla.cadena_maxima(), 3);
+        assert_eq!(tabla.buscar(0, 8), (Some(2), 2));
+        assert_eq!(tabla.buscar(0, 0), (Some(0), 4));
+        assert_eq!(tabla.buscar(0, 12), (None, 4));
+    }
+
+    #[test]
+    fn quitar_en_medio_de_la_cadena() {
+        let mut tabla = cadena_de_tres();
+        tabla.quitar(1);
+        assert_eq!(tabla.pagina_en(1), None);
+        assert_eq!(tabla.buscar(0, 4), (None, 3));
+        assert_eq!(tabla.buscar(0, 0), (Some(0), 3));
+        assert_eq!(tabla.buscar(0, 8), (Some(2), 2));
+        assert_eq!(tabla.cadena_maxima(), 2);
+    }
+
+    #[test]
+    fn quitar_la_cabeza_y_la_cola() {
+        let mut tabla = cadena_de_tres();
+        tabla.quitar(2);
+        assert_eq!(tabla.buscar(0, 4), (Some(1), 2));
+        tabla.quitar(0);
+        assert_eq!(tabla.buscar(0, 4), (Some(1), 2));
+        assert_eq!(tabla.cadena_maxima(), 1);
+        // Quitar un marco vacío no toca la tabla
+        tabla.quitar(3);
+        assert_eq!(tabla.pagina_en(1), Some((0, 4)));
+    }
+
+    #[test]
+    fn la_tabla_hash_resuelve_el_marco_en_el_nodo() {
+        let mut tabla = TablaHash::new(4);
+        tabla.insertar(0, 0, Some(5));
+        tabla.insertar(0, 4, None);
+        assert_eq!(tabla.buscar(0, 4), (None, 2));
+        assert_eq!(tabla.buscar(0, 0), (Some(5), 3));
+        tabla.asignar_marco(0, 4, Some(1));
+        tabla.asignar_marco(0, 0, None);
+        assert_eq!(tabla.buscar(0, 4), (Some(1), 2));
+        assert_eq!(tabla.buscar(0, 0), (None, 3));
+        tabla.quitar_proceso(0);
+        assert_eq!(tabla.nodos(), 0);
+        assert!(!tabla.contiene(0, 4));
+    }
+}
@@ -0,0 +1,344 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// --- TLB (TRANSLATION LOOKASIDE BUFFER) ---
+
+/// Política de reemplazo dentro de un conjunto de la TLB
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReemplazoTlb {
+    Fifo,      // Sale la entrada cargada hace más tiempo
+    Lru,       // Sale la entrada usada hace más tiempo
+    Aleatorio, // Sale una entrada cualquiera del conjunto
+}
+
+impl ReemplazoTlb {
+    pub const TODOS: [ReemplazoTlb; 3] = [
+        ReemplazoTlb::Fifo,
+        ReemplazoTlb::Lru,
+        ReemplazoTlb::Aleatorio,
+    ];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            ReemplazoTlb::Fifo => "FIFO",
+            ReemplazoTlb::Lru => "LRU",
+            ReemplazoTlb::Aleatorio => "Aleatorio",
+        }
+    }
+
+    /// Busca un reemplazo por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|r| r.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+}
+
+/// Qué hace la TLB cuando la CPU pasa a ejecutar otro proceso
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModoTlb {
+    Vaciado, // Sin etiquetas: se invalida entera en cada cambio de contexto
+    Asid,    // Cada entrada lleva el PID (ASID) y sobrevive al cambio de contexto
+}
+
+impl ModoTlb {
+    pub const TODOS: [ModoTlb; 2] = [ModoTlb::Vaciado, ModoTlb::Asid];
+
+    pub fn nombre(self) -> &'static str {
+        match self {
+            ModoTlb::Vaciado => "Vaciado",
+            ModoTlb::Asid => "ASID",
+        }
+    }
+
+    /// Busca un modo por nombre (sin distinguir mayúsculas)
+    pub fn desde_nombre(nombre: &str) -> Option<Self> {
+        Self::TODOS
+            .into_iter()
+            .find(|m| m.nombre().eq_ignore_ascii_case(nombre.trim()))
+    }
+}
+
+/// Geometría y comportamiento de la TLB (config.ini)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParametrosTlb {
+    pub entradas: usize,         // Entradas totales (0 = sin TLB)
+    pub vias: usize,             // Asociatividad: entradas por conjunto (0 = totalmente asociativa)
+    pub reemplazo: ReemplazoTlb, // Víctima dentro del conjunto
+    pub modo: ModoTlb,           // Vaciado en cambio de contexto o etiquetas ASID
+    pub semilla: u64,            // Semilla del reemplazo Aleatorio (RANDOM_SEED)
+}
+
+impl Default for ParametrosTlb {
+    fn default() -> Self {
+        Self {
+            entradas: 16,
+            vias: 4,
+            reemplazo: ReemplazoTlb::Lru,
+            modo: ModoTlb::Asid,
+            semilla: 42,
+        }
+    }
+}
+
+impl ParametrosTlb {
+    /// Entradas por conjunto (la asociatividad efectiva)
+    pub fn vias_efectivas(&self) -> usize {
+        if self.vias == 0 || self.vias > self.entradas {
+            self.entradas
+        } else {
+            self.vias
+        }
+    }
+
+    /// Número de conjuntos en que se divide la TLB
+    pub fn conjuntos(&self) -> usize {
+        self.entradas
+            .checked_div(self.vias_efectivas())
+            .unwrap_or(0)
+    }
+}
+
+/// Latencias para calcular el tiempo efectivo de acceso (EAT), en nanosegundos
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LatenciasAcceso {
+    pub memoria_ns: f64, // Un acceso a RAM (tabla de páginas o dato)
+    pub tlb_ns: f64,     // Una consulta a la TLB
+    pub fallo_ns: f64,   // Servicio de un fallo de página (disco incluido)
+}
+
+impl Default for LatenciasAcceso {
+    fn default() -> Self {
+        Self {
+            memoria_ns: 100.0,
+            tlb_ns: 10.0,
+            fallo_ns: 8_000_000.0,
+        }
+    }
+}
+
+/// Traducción cacheada: página virtual de un proceso -> marco físico
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EntradaTlb {
+    pub pid: usize, // ASID (en modo Vaciado solo hay entradas del proceso actual)
+    pub pagina: usize,
+    pub marco: usize,
+    cargada: u64,    // Instante de inserción (FIFO)
+    ultimo_uso: u64, // Instante del último acierto (LRU)
+}
+
+/// TLB asociativa por conjuntos delante de las tablas de páginas
+pub struct Tlb {
+    pub parametros: ParametrosTlb,
+    conjuntos: Vec<Vec<EntradaTlb>>,
+    reloj: u64,
+    rng: StdRng,
+    ultimo_pid: Option<usize>, // Proceso de la última consulta (detecta cambios de contexto)
+
+    pub aciertos: usize,
+    pub fallos: usize,
+    pub vaciados: usize, // Vaciados completos por cambio de contexto
+}
+
+impl Tlb {
+    pub fn new(parametros: ParametrosTlb) -> Self {
+        Self {
+            parametros,
+            conjuntos: vec![Vec::new(); parametros.conjuntos()],
+            reloj: 0,
+            rng: StdRng::seed_from_u64(parametros.semilla),
+            ultimo_pid: None,
+            aciertos: 0,
+            fallos: 0,
+            vaciados: 0,
+        }
+    }
+
+    /// Conjunto en que debe estar la página (bits bajos del número de página)
+    fn conjunto(&self, pagina: usize) -> Option<usize> {
+        (!self.conjuntos.is_empty()).then(|| pagina % self.conjuntos.len())
+    }
+
+    /// Consulta la TLB para una referencia de `pid`. Cuenta acierto o fallo y,
+    /// en modo Vaciado, invalida todo si cambia el proceso en ejecución.
+    pub fn buscar(&mut self, pid: usize, pagina: usize) -> Option<usize> {
+        if self.parametros.modo == ModoTlb::Vaciado
+            && self.ultimo_pid.is_some_and(|anterior| anterior != pid)
+        {
+            self.vaciar();
+            self.vaciados += 1;
+        }
+        self.ultimo_pid = Some(pid);
+        self.reloj += 1;
+
+        let reloj = self.reloj;
+        let encontrada = self.conjunto(pagina).and_then(|c| {
+            self.conjuntos[c]
+                .iter_mut()
+                .find(|e| e.pid == pid && e.pagina == pagina)
+        });
+        match encontrada {
+            Some(entrada) => {
+                entrada.ultimo_uso = reloj;
+                self.aciertos += 1;
+                Some(entrada.marco)
+            }
+            None => {
+                self.fallos += 1;
+                None
+            }
+        }
+    }
+
+    /// Guarda la traducción tras recorrer la tabla de páginas
+    pub fn insertar(&mut self, pid: usize, pagina: usize, marco: usize) {
+        let Some(c) = self.conjunto(pagina) else {
+            return;
+        };
+        self.invalidar(pid, pagina);
+        let entrada = EntradaTlb {
+            pid,
+            pagina,
+            marco,
+            cargada: self.reloj,
+            ultimo_uso: self.reloj,
+        };
+
+        let vias = self.parametros.vias_efectivas();
+        if self.conjuntos[c].len() < vias {
+            self.conjuntos[c].push(entrada);
+            return;
+        }
+        let conjunto = &self.conjuntos[c];
+        let victima = match self.parametros.reemplazo {
+            ReemplazoTlb::Fifo => (0..vias).min_by_key(|&i| conjunto[i].cargada),
+            ReemplazoTlb::Lru => (0..vias).min_by_key(|&i| conjunto[i].ultimo_uso),
+            ReemplazoTlb::Aleatorio => Some(self.rng.gen_range(0..vias)),
+        };
+        if let Some(i) = victima {
+            self.conjuntos[c][i] = entrada;
+        }
+    }
+
+    /// Invalida la traducción de una página (desalojada de la RAM)
+    pub fn invalidar(&mut self, pid: usize, pagina: usize) {
+        if let Some(c) = self.conjunto(pagina) {
+            self.conjuntos[c].retain(|e| (e.pid, e.pagina) != (pid, pagina));
+        }
+    }
+
+    /// Invalida todas las traducciones de un proceso terminado
+    pub fn invalidar_proceso(&mut self, pid: usize) {
+        for conjunto in &mut self.conjuntos {
+            conjunto.retain(|e| e.pid != pid);
+        }
+    }
+
+    /// Invalida la TLB completa
+    pub fn vaciar(&mut self) {
+        for conjunto in &mut self.conjuntos {
+            conjunto.clear();
+        }
+    }
+
+    /// Entradas válidas actualmente
+    pub fn entradas(&self) -> impl Iterator<Item = &EntradaTlb> {
+        self.conjuntos.iter().flatten()
+    }
+
+    /// Porcentaje de consultas resueltas por la TLB
+    pub fn tasa_aciertos(&self) -> f64 {
+        let consultas = self.aciertos + self.fallos;
+        if consultas == 0 {
+            return 0.0;
+        }
+        (self.aciertos as f64 / consultas as f64) * 100.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tlb(entradas: usize, vias: usize, reemplazo: ReemplazoTlb, modo: ModoTlb) -> Tlb {
+        Tlb::new(ParametrosTlb {
+            entradas,
+            vias,
+            reemplazo,
+            modo,
+            semilla: 42,
+        })
+    }
+
+    /// Consulta y, si falla, carga la página como haría el gestor
+    fn referenciar(tlb: &mut Tlb, pid: usize, pagina: usize) {
+        if tlb.buscar(pid, pagina).is_none() {
+            tlb.insertar(pid, pagina, 100 + pagina);
+        }
+    }
+
+    fn paginas(tlb: &Tlb) -> Vec<usize> {
+        let mut paginas: Vec<usize> = tlb.entradas().map(|e| e.pagina).collect();
+        paginas.sort_unstable();
+        paginas
+    }
+
+    #[test]
+    fn fifo_y_lru_eligen_victimas_distintas() {
+        let mut resultados = Vec::new();
+        for reemplazo in [ReemplazoTlb::Fifo, ReemplazoTlb::Lru] {
+            // Un conjunto de 2 vías: la página 0 se carga primero pero se usa la última
+            let mut tlb = tlb(2, 2, reemplazo, ModoTlb::Asid);
+            for pagina in [0, 1, 0, 2] {
+                referenciar(&mut tlb, 1, pagina);
+            }
+            resultados.push(paginas(&tlb));
+        }
+        assert_eq!(resultados, [vec![1, 2], vec![0, 2]]);
+    }
+
+    #[test]
+    fn vaciado_en_cambio_de_contexto() {
+        let mut tlb = tlb(4, 4, ReemplazoTlb::Lru, ModoTlb::Vaciado);
+        referenciar(&mut tlb, 1, 0);
+        assert_eq!(tlb.buscar(1, 0), Some(100));
+        assert_eq!(tlb.vaciados, 0);
+
+        assert_eq!(tlb.buscar(2, 0), None);
+        assert_eq!(tlb.vaciados, 1);
+        assert_eq!(tlb.entradas().count(), 0);
+        assert_eq!(tlb.buscar(1, 0), None);
+        assert_eq!(tlb.vaciados, 2);
+    }
+
+    #[test]
+    fn asid_conserva_las_entradas_de_otros_procesos() {
+        let mut tlb = tlb(4, 4, ReemplazoTlb::Lru, ModoTlb::Asid);
+        referenciar(&mut tlb, 1, 0);
+        referenciar(&mut tlb, 2, 0);
+        assert_eq!(tlb.buscar(1, 0), Some(100));
+        assert_eq!(tlb.buscar(2, 0), Some(100));
+        assert_eq!(tlb.vaciados, 0);
+        assert_eq!((tlb.aciertos, tlb.fallos), (2, 2));
+    }
+
+    #[test]
+    fn cero_vias_es_totalmente_asociativa() {
+        let parametros = ParametrosTlb {
+            vias: 0,
+            ..ParametrosTlb::default()
+        };
+        assert_eq!(parametros.vias_efectivas(), parametros.entradas);
+        assert_eq!(parametros.conjuntos(), 1);
+
+        // Con correspondencia directa las páginas 0, 4, 8 y 12 comparten conjunto
+        let mut directa = tlb(4, 1, ReemplazoTlb::Lru, ModoTlb::Asid);
+        let mut asociativa = tlb(4, 0, ReemplazoTlb::Lru, ModoTlb::Asid);
+        for pagina in [0, 4, 8, 12] {
+            referenciar(&mut directa, 1, pagina);
+            referenciar(&mut asociativa, 1, pagina);
+        }
+        assert_eq!(paginas(&directa), [12]);
+        assert_eq!(paginas(&asociativa), [0, 4, 8, 12]);
+    }
+}
@@ -1,13 +1,39 @@
-use crate::models::GestorMemoria;
+use memory_sim::{
+    AsignacionInicial, EstadoSlab, GestorMemoria, ModoMemoria, NodoBuddy, OrganizacionTabla,
+};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout},
     style::{Color, Modifier, Style, Stylize},
-    widgets::{Block, BorderType, Borders, List, ListItem, Paragraph},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, Paragraph},
     Frame,
 };
 
+/// Diálogo de traducción de direcciones (tecla T)
+#[derive(Default)]
+pub struct DialogoTraduccion {
+    pub entrada: Option<String>, // Texto tecleado ("PID DIRECCION"); None = no se está escribiendo
+    pub resultado: Vec<String>,  // Pasos de la última traducción
+}
+
+impl DialogoTraduccion {
+    pub fn visible(&self) -> bool {
+        self.entrada.is_some() || !self.resultado.is_empty()
+    }
+
+    pub fn cerrar(&mut self) {
+        self.entrada = None;
+        self.resultado.clear();
+    }
+}
+
 /// Renderizado principal de la interfaz estilo htop profesional
-pub fn dibujar(f: &mut Frame, gestor: &GestorMemoria, pausado: bool, modo_auto: bool) {
+pub fn dibujar(
+    f: &mut Frame,
+    gestor: &GestorMemoria,
+    pausado: bool,
+    modo_auto: bool,
+    dialogo: &DialogoTraduccion,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -31,7 +57,11 @@ pub fn dibujar(f: &mut Frame, gestor: &GestorMemoria, pausado: bool, modo_auto:
         .constraints([Constraint::Percentage(70), Constraint::Percentage(30)])
         .split(chunks[2]);
 
-    dibujar_mapa_ram(f, gestor, main_layout[0]);
+    match gestor.modo {
+        ModoMemoria::Paginacion => dibujar_mapa_ram(f, gestor, main_layout[0]),
+        ModoMemoria::Contigua => dibujar_mapa_contiguo(f, gestor, main_layout[0]),
+        ModoMemoria::Buddy => dibujar_mapa_buddy(f, gestor, main_layout[0]),
+    }
     dibujar_panel_info(f, gestor, main_layout[1]);
 
     // BOTTOM
@@ -45,6 +75,50 @@ pub fn dibujar(f: &mut Frame, gestor: &GestorMemoria, pausado: bool, modo_auto:
 
     // FOOTER
     dibujar_footer(f, chunks[4]);
+
+    if dialogo.visible() {
+        dibujar_dialogo_traduccion(f, dialogo);
+    }
+}
+
+/// Ventana superpuesta con la entrada de la traducción y sus pasos
+fn dibujar_dialogo_traduccion(f: &mut Frame, dialogo: &DialogoTraduccion) {
+    let mut lineas = Vec::new();
+    match &dialogo.entrada {
+        Some(entrada) => {
+            lineas.push("Escriba PID y dirección virtual (decimal o 0x hex):".to_string());
+            lineas.push(format!("> {}_", entrada));
+        }
+        None => lineas.extend(dialogo.resultado.iter().cloned()),
+    }
+    lineas.push(String::new());
+    lineas.push(match dialogo.entrada {
+        Some(_) => "Enter: Traducir | Esc: Cancelar".to_string(),
+        None => "T: Otra dirección | Esc: Cerrar".to_string(),
+    });
+
+    let pantalla = f.size();
+    let ancho = (pantalla.width * 4 / 5).max(20).min(pantalla.width);
+    let alto = (lineas.len() as u16 + 2).min(pantalla.height);
+    let area = ratatui::layout::Rect {
+        x: (pantalla.width - ancho) / 2,
+        y: (pantalla.height - alto) / 2,
+        width: ancho,
+        height: alto,
+    };
+
+    let bloque = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .title(" Traducción de Direcciones ")
+        .style(Style::default().fg(Color::Cyan));
+
+    let parrafo = Paragraph::new(lineas.join("\n"))
+        .block(bloque)
+        .style(Style::default().fg(Color::White));
+
+    f.render_widget(Clear, area);
+    f.render_widget(parrafo, area);
 }
 
 /// Barra superior con título y estado
@@ -59,8 +133,11 @@ fn dibujar_top_bar(
     let modo = if modo_auto { "AUTOMATICO" } else { "MANUAL" };
 
     let texto = format!(
-        " Gestor de Memoria v1.0 | Algoritmo: {:?} | Modo: {} | Estado: {} ",
-        gestor.algoritmo, modo, estado
+        " Gestor de Memoria v1.0 | Memoria: {} | Algoritmo: {} | Modo: {} | Estado: {} ",
+        gestor.modo.nombre(),
+        gestor.nombre_algoritmo(),
+        modo,
+        estado
     );
 
     let style = if pausado {
@@ -83,17 +160,43 @@ fn dibujar_stats_bar(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layou
     let utilizacion = gestor.utilización_ram();
     let tasa_fallos = gestor.tasa_fallos();
 
-    let stats = vec![
-        format!("Processes: {}/{}", gestor.procesos.len(), 30),
+    let uso_ram = match gestor.modo {
+        ModoMemoria::Paginacion => format!(
+            "RAM Usage: {}/{} frames ({} kernel, {:.1}%)",
+            marcos_usados,
+            total_marcos,
+            gestor.slab.marcos().count(),
+            utilizacion
+        ),
+        ModoMemoria::Contigua => format!(
+            "RAM Usage: {}/{} KB ({:.1}%)",
+            gestor.contigua.tamaño_kb - gestor.contigua.libre_total(),
+            gestor.contigua.tamaño_kb,
+            utilizacion
+        ),
+        ModoMemoria::Buddy => format!(
+            "RAM Usage: {}/{} KB ({:.1}%)",
+            gestor.buddy.tamaño_kb - gestor.buddy.libre_total(),
+            gestor.buddy.tamaño_kb,
+            utilizacion
+        ),
+    };
+
+    let stats = [
         format!(
-            "RAM Usage: {}/{} frames ({:.1}%)",
-            marcos_usados, total_marcos, utilizacion
+            "Processes: {}/{}",
+            gestor.procesos.len(),
+            gestor.max_procesos
         ),
+        uso_ram,
         format!(
             "Page Faults: {} ({:.2}%)",
             gestor.fallos_pagina, tasa_fallos
         ),
-        format!("Swaps: {}", gestor.swaps_realizados),
+        format!(
+            "Swaps: {} out / {} in",
+            gestor.swaps_salida, gestor.swaps_entrada
+        ),
     ];
 
     let bloque = Block::default()
@@ -112,10 +215,24 @@ fn dibujar_stats_bar(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layou
 
 /// Mapa de memoria RAM estilo tabla
 fn dibujar_mapa_ram(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout::Rect) {
+    // Manecillas de la política activa (Reloj, WSClock, CLOCK-Pro...)
+    let manecillas = gestor.politica.manecillas();
+    let mut titulo = " Mapa de Memoria Física (RAM) ".to_string();
+    if !manecillas.is_empty() {
+        let posiciones: Vec<String> = manecillas
+            .iter()
+            .map(|(etiqueta, marco)| format!("{}={}", etiqueta, marco))
+            .collect();
+        titulo = format!(
+            " Mapa de Memoria Física (RAM) | Manecillas: {} ",
+            posiciones.join(" ")
+        );
+    }
+
     let bloque = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Double)
-        .title(" Mapa de Memoria Física (RAM) ")
+        .title(titulo)
         .style(Style::default().fg(Color::Green));
 
     let area_interna = bloque.inner(area);
@@ -154,32 +271,41 @@ fn dibujar_mapa_ram(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout
             continue;
         }
 
-        let (texto, estilo) = if let Some(pid) = marco.proceso_id {
-            let color = gestor
-                .procesos
-                .iter()
-                .find(|p| p.pid == pid)
-                .map(|p| {
-                    // Colores más profesionales y sutiles
-                    let base = (p.color.0 as u16 + p.color.1 as u16 + p.color.2 as u16) / 3;
-                    if base > 180 {
-                        Color::Cyan
-                    } else if base > 100 {
-                        Color::Blue
-                    } else {
-                        Color::Magenta
-                    }
-                })
-                .unwrap_or(Color::White);
-
+        let (mut texto, mut estilo) = if let Some(pid) = marco.proceso_id {
             (
                 format!("P{:02}", pid),
-                Style::default().fg(color).add_modifier(Modifier::BOLD),
+                Style::default()
+                    .fg(color_proceso(gestor, pid))
+                    .add_modifier(Modifier::BOLD),
+            )
+        } else if marco.nucleo {
+            // Marco del núcleo: inicial de la caché dueña del slab
+            let cache = gestor
+                .slab
+                .cache_de_marco(marco.id)
+                .map_or("?", |c| c.nombre.get(..3).unwrap_or(&c.nombre));
+            (
+                format!("K:{}", cache),
+                Style::default()
+                    .fg(Color::White)
+                    .bg(Color::Red)
+                    .add_modifier(Modifier::BOLD),
             )
         } else {
             ("--".to_string(), Style::default().fg(Color::DarkGray))
         };
 
+        // Marcar los marcos señalados por alguna manecilla
+        let etiquetas: String = manecillas
+            .iter()
+            .filter(|&&(_, idx)| idx == i)
+            .map(|&(etiqueta, _)| etiqueta)
+            .collect();
+        if !etiquetas.is_empty() {
+            texto = format!("{}>{}", etiquetas, texto);
+            estilo = estilo.add_modifier(Modifier::REVERSED);
+        }
+
         let celda = Paragraph::new(texto)
             .alignment(Alignment::Center)
             .style(estilo);
@@ -188,23 +314,334 @@ fn dibujar_mapa_ram(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout
     }
 }
 
+/// Color de las celdas de un proceso en los mapas de memoria
+fn color_proceso(gestor: &GestorMemoria, pid: usize) -> Color {
+    gestor
+        .procesos
+        .iter()
+        .find(|p| p.pid == pid)
+        .map(|p| {
+            // Colores más profesionales y sutiles
+            let base = (p.color.0 as u16 + p.color.1 as u16 + p.color.2 as u16) / 3;
+            if base > 180 {
+                Color::Cyan
+            } else if base > 100 {
+                Color::Blue
+            } else {
+                Color::Magenta
+            }
+        })
+        .unwrap_or(Color::White)
+}
+
+/// Mapa de la memoria contigua: cada celda es un tramo de KB y muestra el
+/// proceso que lo ocupa o `--` si forma parte de un hueco
+fn dibujar_mapa_contiguo(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout::Rect) {
+    let memoria = &gestor.contigua;
+    let columnas = 8;
+    let celdas = gestor.marcos_ram.len().max(columnas);
+    let unidad = memoria.tamaño_kb.div_ceil(celdas).max(1);
+
+    let bloque = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .title(format!(
+            " Memoria Contigua ({}) | Huecos: {} | {} KB por celda ",
+            memoria.estrategia.nombre(),
+            memoria.huecos().count(),
+            unidad
+        ))
+        .style(Style::default().fg(Color::Green));
+
+    let area_interna = bloque.inner(area);
+    f.render_widget(bloque, area);
+
+    let filas = celdas
+        .div_ceil(columnas)
+        .min(area_interna.height as usize / 2);
+    let grid = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(vec![Constraint::Length(2); filas])
+        .split(area_interna);
+
+    for (fila_idx, fila) in grid.iter().enumerate() {
+        let fila_layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, columnas as u32); columnas])
+            .split(*fila);
+
+        for (col_idx, celda_area) in fila_layout.iter().enumerate() {
+            let inicio = (fila_idx * columnas + col_idx) * unidad;
+            if inicio >= memoria.tamaño_kb {
+                break;
+            }
+            // La celda se atribuye al bloque que cubre su punto medio
+            let medio = (inicio + unidad / 2).min(memoria.tamaño_kb - 1);
+            let (texto, estilo) = match memoria.bloque_en(medio).and_then(|b| b.pid) {
+                Some(pid) => (
+                    format!("P{:02}", pid),
+                    Style::default()
+                        .fg(color_proceso(gestor, pid))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                None => ("--".to_string(), Style::default().fg(Color::DarkGray)),
+            };
+            let celda = Paragraph::new(texto)
+                .alignment(Alignment::Center)
+                .style(estilo);
+            f.render_widget(celda, *celda_area);
+        }
+    }
+}
+
+/// Árbol del sistema buddy (cada nodo con su rango en KB: partido, libre o
+/// del proceso que lo ocupa) y, al lado, la lista libre de cada orden
+fn dibujar_mapa_buddy(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout::Rect) {
+    let buddy = &gestor.buddy;
+    let bloque = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Double)
+        .title(format!(
+            " Buddy System | Raíz {} KB | Mínimo {} KB | Libre {} KB ",
+            buddy.tamaño_kb,
+            1usize << buddy.orden_minimo,
+            buddy.libre_total()
+        ))
+        .style(Style::default().fg(Color::Green));
+
+    let area_interna = bloque.inner(area);
+    f.render_widget(bloque, area);
+
+    let columnas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+        .split(area_interna);
+
+    let arbol: Vec<ListItem> = buddy
+        .arbol()
+        .into_iter()
+        .map(|(profundidad, inicio, orden, nodo)| {
+            let rango = format!(
+                "{}[{}, {}) {}KB",
+                "  ".repeat(profundidad),
+                inicio,
+                inicio + (1 << orden),
+                1usize << orden
+            );
+            let (detalle, estilo) = match nodo {
+                NodoBuddy::Dividido => (String::new(), Style::default().fg(Color::White)),
+                NodoBuddy::Libre => (" libre".to_string(), Style::default().fg(Color::DarkGray)),
+                NodoBuddy::Asignado(a) => (
+                    format!(
+                        " P{:02} {}KB (+{}KB)",
+                        a.pid,
+                        a.solicitado,
+                        a.fragmentacion_interna()
+                    ),
+                    Style::default()
+                        .fg(color_proceso(gestor, a.pid))
+                        .add_modifier(Modifier::BOLD),
+                ),
+            };
+            ListItem::new(rango + &detalle).style(estilo)
+        })
+        .collect();
+    f.render_widget(
+        List::new(arbol).block(
+            Block::default()
+                .borders(Borders::RIGHT)
+                .title(" Árbol ")
+                .style(Style::default().fg(Color::Green)),
+        ),
+        columnas[0],
+    );
+
+    let listas: Vec<ListItem> = buddy
+        .listas_libres()
+        .map(|(orden, inicios)| {
+            let contenido = if inicios.is_empty() {
+                "-".to_string()
+            } else {
+                inicios
+                    .iter()
+                    .map(|i| i.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let color = if inicios.is_empty() {
+                Color::DarkGray
+            } else {
+                Color::White
+            };
+            ListItem::new(format!("{:>5} KB: {}", 1usize << orden, contenido))
+                .style(Style::default().fg(color))
+        })
+        .collect();
+    f.render_widget(
+        List::new(listas).block(
+            Block::default()
+                .title(" Listas libres ")
+                .style(Style::default().fg(Color::Green)),
+        ),
+        columnas[1],
+    );
+}
+
 /// Panel de información lateral
 fn dibujar_panel_info(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout::Rect) {
+    let estado_politica = gestor.politica.estado();
+    let alto_politica = if estado_politica.is_empty() {
+        0
+    } else {
+        estado_politica.len() as u16 + 2
+    };
+
+    // Cachés slab del núcleo: solo mientras ocupan algún marco
+    let caches_slab: Vec<ListItem> = if gestor.slab.marcos().next().is_some() {
+        gestor
+            .slab
+            .caches
+            .iter()
+            .map(|c| {
+                ListItem::new(format!(
+                    "{} {}B: L{} P{} V{} | {}/{} obj ({:.1}%)",
+                    c.nombre,
+                    c.tamaño_objeto,
+                    c.contar(EstadoSlab::Lleno),
+                    c.contar(EstadoSlab::Parcial),
+                    c.contar(EstadoSlab::Vacio),
+                    c.objetos_en_uso(),
+                    c.slabs.len() * c.objetos_por_slab,
+                    c.utilizacion()
+                ))
+                .style(Style::default().fg(Color::White))
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let alto_slab = if caches_slab.is_empty() {
+        0
+    } else {
+        caches_slab.len() as u16 + 2
+    };
+
     let layout = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(6), // Metrics
-            Constraint::Min(4),    // Process list
+            Constraint::Length(18),            // Metrics
+            Constraint::Length(alto_politica), // Policy internals
+            Constraint::Length(alto_slab),     // Kernel slab caches
+            Constraint::Min(4),                // Process list
         ])
         .split(area);
 
     // Métricas detalladas
-    let metricas = vec![
+    let metricas_paginacion = [
         format!("Accesos Totales: {}", gestor.accesos_totales),
         format!("Fallos de Página: {}", gestor.fallos_pagina),
+        format!(
+            "Últimas {} refs: {} fallos (Óptimo: {})",
+            gestor.historial_referencias.len(),
+            gestor.fallos_ventana(),
+            gestor.fallos_optimos()
+        ),
         format!("Tasa de Fallos: {:.2}%", gestor.tasa_fallos()),
-        format!("Swaps Realizados: {}", gestor.swaps_realizados),
+        format!(
+            "TLB ({}): {:.2}% aciertos ({}/{})",
+            gestor.tlb.parametros.modo.nombre(),
+            gestor.tlb.tasa_aciertos(),
+            gestor.tlb.aciertos,
+            gestor.tlb.aciertos + gestor.tlb.fallos
+        ),
+        format!("EAT: {:.1} ns", gestor.tiempo_acceso_efectivo()),
+        format!(
+            "Tabla {} ({} niv.): {:.1} KB (lineal {:.0} KB)",
+            gestor.organizacion.nombre(),
+            gestor.tabla_paginas.niveles(),
+            gestor.memoria_tablas_total() as f64 / 1024.0,
+            (gestor.tabla_paginas.memoria_lineal() * gestor.procesos.len()) as f64 / 1024.0
+        ),
+        format!("Accesos a tablas de páginas: {}", gestor.referencias_tabla),
+        format!(
+            "Accesos/búsqueda J/I/H: {:.2} / {:.2} / {:.2}",
+            gestor.coste_busqueda(OrganizacionTabla::Jerarquica).media(),
+            gestor.coste_busqueda(OrganizacionTabla::Invertida).media(),
+            gestor.coste_busqueda(OrganizacionTabla::Hash).media()
+        ),
+        format!(
+            "Cadena máx. I/H: {} / {} (peor búsqueda {} / {})",
+            gestor.tabla_invertida.cadena_maxima(),
+            gestor.tabla_hash.cadena_maxima(),
+            gestor.coste_busqueda(OrganizacionTabla::Invertida).maximo,
+            gestor.coste_busqueda(OrganizacionTabla::Hash).maximo
+        ),
+        format!("Swap-outs (a Swap): {}", gestor.swaps_salida),
+        format!("Swap-ins (desde Swap): {}", gestor.swaps_entrada),
+        format!("Write-backs (bit M): {}", gestor.escrituras_swap),
+        format!(
+            "Alcance: {} (reparto {})",
+            gestor.alcance.nombre(),
+            gestor.asignacion.nombre()
+        ),
+        format!(
+            "Marcos robados a otros: {} (núcleo: {})",
+            gestor.robos_marcos, gestor.robos_nucleo
+        ),
+        format!(
+            "Frag. interna (última página): {} KB",
+            gestor.fragmentacion_interna_total()
+        ),
     ];
+    let memoria = &gestor.contigua;
+    let metricas_contigua = [
+        format!("Estrategia: {}", memoria.estrategia.nombre()),
+        format!(
+            "Libre: {} KB en {} huecos",
+            memoria.libre_total(),
+            memoria.huecos().count()
+        ),
+        format!("Mayor hueco: {} KB", memoria.mayor_hueco()),
+        format!(
+            "Fragmentación externa: {:.1}%",
+            memoria.fragmentacion_externa()
+        ),
+        format!("Asignaciones fallidas: {}", memoria.asignaciones_fallidas),
+        format!(
+            "Compactaciones: {} ({} KB movidos)",
+            memoria.compactaciones, memoria.kb_movidos
+        ),
+    ];
+    let buddy = &gestor.buddy;
+    let asignado_buddy = buddy.tamaño_kb - buddy.libre_total();
+    let metricas_buddy = [
+        format!(
+            "Bloques: mínimo {} KB, raíz {} KB",
+            1usize << buddy.orden_minimo,
+            buddy.tamaño_kb
+        ),
+        format!(
+            "Libre: {} KB en {} bloques",
+            buddy.libre_total(),
+            buddy.listas_libres().map(|(_, l)| l.len()).sum::<usize>()
+        ),
+        format!("Mayor bloque libre: {} KB", buddy.mayor_bloque_libre()),
+        format!(
+            "Fragmentación interna: {} KB ({:.1}% de lo asignado)",
+            buddy.fragmentacion_interna(),
+            if asignado_buddy == 0 {
+                0.0
+            } else {
+                buddy.fragmentacion_interna() as f64 / asignado_buddy as f64 * 100.0
+            }
+        ),
+        format!("Asignaciones fallidas: {}", buddy.asignaciones_fallidas),
+    ];
+    let metricas: &[String] = match gestor.modo {
+        ModoMemoria::Paginacion => &metricas_paginacion,
+        ModoMemoria::Contigua => &metricas_contigua,
+        ModoMemoria::Buddy => &metricas_buddy,
+    };
 
     let bloque_metricas = Block::default()
         .borders(Borders::ALL)
@@ -218,19 +655,75 @@ fn dibujar_panel_info(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layo
 
     f.render_widget(parrafo_metricas, layout[0]);
 
+    // Estado interno de la política (listas de ARC/2Q, parámetro adaptativo...)
+    if !estado_politica.is_empty() {
+        let bloque_politica = Block::default()
+            .borders(Borders::ALL)
+            .border_type(BorderType::Plain)
+            .title(format!(" Política {} ", gestor.nombre_algoritmo()))
+            .style(Style::default().fg(Color::Green));
+
+        let parrafo_politica = Paragraph::new(estado_politica.join("\n"))
+            .block(bloque_politica)
+            .style(Style::default().fg(Color::White));
+
+        f.render_widget(parrafo_politica, layout[1]);
+    }
+
+    // Slabs llenos (L), parciales (P) y vacíos (V) de cada caché del núcleo
+    if !caches_slab.is_empty() {
+        let lista_slab = List::new(caches_slab).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Plain)
+                .title(" Slab del Núcleo ")
+                .style(Style::default().fg(Color::Red)),
+        );
+        f.render_widget(lista_slab, layout[2]);
+    }
+
     // Lista de procesos activos
     let procesos: Vec<ListItem> = gestor
         .procesos
         .iter()
         .map(|p| {
-            let paginas_en_ram = p.paginas.iter().filter(|pg| pg.marco_id.is_some()).count();
-            let texto = format!(
-                "PID {:02} | {}KB | {}/{} pgs",
+            if let Some(bloque) = gestor.contigua.bloque_de(p.pid) {
+                return ListItem::new(format!(
+                    "PID {:02} | {}KB | base {} KB | límite {} KB",
+                    p.pid, p.tamaño_kb, bloque.inicio, bloque.tamaño
+                ))
+                .style(Style::default().fg(Color::White));
+            }
+            if let Some((inicio, bloque)) = gestor.buddy.bloque_de(p.pid) {
+                return ListItem::new(format!(
+                    "PID {:02} | {}KB | bloque {} KB @{} | frag. int. {} KB",
+                    p.pid,
+                    p.tamaño_kb,
+                    bloque.tamaño(),
+                    inicio,
+                    bloque.fragmentacion_interna()
+                ))
+                .style(Style::default().fg(Color::White));
+            }
+            let paginas_en_ram = p.paginas_en_ram();
+            let mut texto = format!(
+                "PID {:02} | {}KB | {}/{} pgs | WS {} | F {}",
                 p.pid,
                 p.tamaño_kb,
                 paginas_en_ram,
-                p.paginas.len()
+                p.paginas.len(),
+                p.working_set(gestor.parametros.ventana_ws),
+                p.fallos_pagina
             );
+            if let Some(desperdicio) = gestor.fragmentacion_interna(p.pid) {
+                texto.push_str(&format!(" | FI {}KB", desperdicio));
+            }
+            if let Some(cuota) = p.cuota_marcos {
+                texto.push_str(&format!(" | Cuota {}", cuota));
+                if gestor.asignacion == AsignacionInicial::Prioridad {
+                    texto.push_str(&format!(" (pri {})", p.prioridad));
+                }
+            }
             ListItem::new(texto).style(Style::default().fg(Color::White))
         })
         .collect();
@@ -238,11 +731,15 @@ fn dibujar_panel_info(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layo
     let bloque_procesos = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
-        .title(format!(" Procesos Activos ({}) ", gestor.procesos.len()))
+        .title(format!(
+            " Procesos Activos ({}){} ",
+            gestor.procesos.len(),
+            if gestor.pff.is_some() { " | PFF" } else { "" }
+        ))
         .style(Style::default().fg(Color::Cyan));
 
     let lista = List::new(procesos).block(bloque_procesos);
-    f.render_widget(lista, layout[1]);
+    f.render_widget(lista, layout[3]);
 }
 
 /// Logs del sistema sin emojis
@@ -291,7 +788,11 @@ fn dibujar_swap(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout::Re
     let bloque = Block::default()
         .borders(Borders::ALL)
         .border_type(BorderType::Plain)
-        .title(format!(" Área de Swap ({}/50) ", gestor.cola_swap.len()))
+        .title(format!(
+            " Área de Swap ({}/{}) ",
+            gestor.cola_swap.len(),
+            gestor.capacidad_swap
+        ))
         .style(Style::default().fg(Color::Magenta));
 
     let lista = List::new(swap_items).block(bloque);
@@ -301,7 +802,7 @@ fn dibujar_swap(f: &mut Frame, gestor: &GestorMemoria, area: ratatui::layout::Re
 /// Barra de footer con controles
 fn dibujar_footer(f: &mut Frame, area: ratatui::layout::Rect) {
     let controles =
-        " Q:Salir | P:Pausar | N:Nuevo Proceso | K:Matar Proceso | 1/2/3:Algoritmo | A:Cambiar Modo ";
+        " Q:Salir | P:Pausar | N:Nuevo Proceso | K:Matar Proceso | 0-9/Tab:Algoritmo | S:Alcance | V:Verificar | T:Traducir | L:Niveles | O:Tabla | M:Memoria | F:Ajuste | C:Compactar | B/D/R:Slab | A:Cambiar Modo ";
 
     let footer = Paragraph::new(controles)
         .style(Style::default().bg(Color::DarkGray).fg(Color::White))